@@ -0,0 +1,148 @@
+//! Arbitrary-precision storage types from [`num_bigint`]
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{pow, Signed as _};
+
+use crate::{AbsAndSign, EQSupported, EngineeringQuantity, SignHelper};
+
+/// <div class="warning">
+/// Available on feature <b>bigint</b> only.
+/// </div>
+impl EQSupported<BigInt> for BigInt {
+    fn minimum() -> Option<BigInt> {
+        None
+    }
+    fn maximum() -> Option<BigInt> {
+        None
+    }
+    fn is_unsigned() -> bool {
+        false
+    }
+}
+
+/// <div class="warning">
+/// Available on feature <b>bigint</b> only.
+/// </div>
+impl EQSupported<BigUint> for BigUint {
+    fn minimum() -> Option<BigUint> {
+        Some(BigUint::ZERO)
+    }
+    fn maximum() -> Option<BigUint> {
+        None
+    }
+    fn is_unsigned() -> bool {
+        true
+    }
+}
+
+impl SignHelper<BigInt> for BigInt {
+    fn abs_and_sign(&self) -> AbsAndSign<BigInt> {
+        AbsAndSign {
+            abs: self.abs(),
+            negative: self.is_negative(),
+        }
+    }
+}
+
+impl SignHelper<BigUint> for BigUint {
+    fn abs_and_sign(&self) -> AbsAndSign<BigUint> {
+        AbsAndSign {
+            abs: self.clone(),
+            negative: false,
+        }
+    }
+}
+
+macro_rules! impl_from_big {
+    {$($t:ty),+} => {$(
+        /// <div class="warning">
+        /// Available on feature <b>bigint</b> only.
+        /// </div>
+        impl From<EngineeringQuantity<$t>> for $t {
+            /// Conversion to a big integer is infallible.
+            ///
+            /// <div class="danger">
+            /// This is a lossy conversion, any fractional part will be truncated.
+            /// </div>
+            fn from(eq: EngineeringQuantity<$t>) -> Self {
+                let factor = pow(<$t>::from(1000u16), eq.exponent.unsigned_abs().into());
+                if eq.exponent > 0 {
+                    eq.significand * factor
+                } else {
+                    eq.significand / factor
+                }
+            }
+        }
+    )+}
+}
+
+impl_from_big!(BigInt, BigUint);
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use num_bigint::{BigInt, BigUint};
+    use num_rational::Ratio;
+
+    use crate::DecimalQuantity as DQ;
+    use crate::EngineeringQuantity as EQ;
+    use crate::Error;
+
+    #[test]
+    fn beyond_i128() {
+        // i128 tops out around 170 million Q
+        let _ = EQ::<i128>::from_str("171000000Q").unwrap_err();
+        let big = EQ::<BigInt>::from_str("171000000Q").unwrap();
+        assert_eq!(big.to_string(), "1.71e38");
+        assert_eq!(
+            BigInt::from(big),
+            BigInt::from_str("171000000000000000000000000000000000000").unwrap()
+        );
+        let neg = EQ::<BigInt>::from_str("-4.7q").unwrap();
+        assert_eq!(neg.to_string(), "-4.7q");
+        let unsigned = EQ::<BigUint>::from_str("12.345k").unwrap();
+        assert_eq!(unsigned.with_precision(0).to_string(), "12.345k");
+        assert_eq!(
+            EQ::<BigUint>::from_str("-1").unwrap_err(),
+            Error::ParseError
+        );
+    }
+
+    #[test]
+    fn compare() {
+        let a = EQ::<BigInt>::from_str("1000Q").unwrap();
+        let b = EQ::<BigInt>::from_str("999999R").unwrap();
+        assert!(a > b);
+        assert_eq!(a, EQ::<BigInt>::from_str("1000000R").unwrap());
+    }
+
+    #[test]
+    fn decimal() {
+        let q = EQ::from_raw(BigInt::from(1), 42).unwrap();
+        let d = DQ::try_from(q.clone()).unwrap();
+        assert_eq!(d.clone().to_raw(), (BigInt::from(1), 126));
+        assert_eq!(EQ::try_from(d).unwrap(), q);
+        // 1e150 and 1e-150 have no i8 power of ten
+        let q = EQ::from_raw(BigInt::from(1), 50).unwrap();
+        assert_eq!(DQ::try_from(q).unwrap_err(), Error::Overflow);
+        let q = EQ::from_raw(BigInt::from(1), -50).unwrap();
+        assert_eq!(DQ::try_from(q).unwrap_err(), Error::Underflow);
+    }
+
+    #[test]
+    fn ratio() {
+        let q = EQ::<BigInt>::from_str("1.5m").unwrap();
+        let r = Ratio::<BigInt>::try_from(q.clone()).unwrap();
+        assert_eq!(r, Ratio::new(BigInt::from(3), BigInt::from(2000)));
+        assert_eq!(EQ::try_from(r).unwrap(), q);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn display_is_total(sig in proptest::num::i64::ANY, exp in proptest::num::i8::ANY) {
+            let ee = EQ::from_raw(BigInt::from(sig), exp).unwrap();
+            let _ = (ee.to_string(), ee.with_precision(0).to_string(), ee.rkm_with_precision(4).to_string());
+        }
+    }
+}
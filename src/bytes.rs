@@ -0,0 +1,368 @@
+//! Byte sizes and data rates, with SI and IEC (binary) prefixes
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::string::{sign_and_digits, Style};
+use crate::{DisplayAdapter, EQSupported, EngineeringQuantity, Error, Parser, PrefixSet};
+
+/// IEC prefixes, for powers of 1024 from 1 to 10
+pub(crate) const IEC_PREFIXES: [&str; 10] =
+    ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi", "Ri", "Qi"];
+
+/// A number of bytes.
+///
+/// Strings may use SI (`1.5GB`) or IEC (`4KiB`) prefixes; the unit `B` is optional.
+/// Output uses SI prefixes unless binary prefixes are requested.
+/// ```
+/// use engineering_repr::ByteSize;
+/// use std::str::FromStr as _;
+/// let size = ByteSize::<u64>::from_str("4KiB").unwrap();
+/// assert_eq!(u64::from(size.0), 4096);
+/// assert_eq!(size.to_string(), "4.09kB");
+/// assert_eq!(size.binary_with_precision(3).to_string(), "4KiB");
+/// assert_eq!(ByteSize::<u64>::from_str("1.5 GB").unwrap().to_string(), "1.5GB");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ByteSize<T: EQSupported<T>>(pub EngineeringQuantity<T>);
+
+/// A data rate, in bits per second.
+///
+/// Strings may use SI (`100Mbit/s`) or IEC (`1Gibit/s`) prefixes.
+/// The units `bit/s`, `b/s` and `bps` are read as bits per second; `B/s` and `Bps` as bytes per second.
+/// A bare `bit`, as in `100Mbit` Ethernet, is also read as bits per second.
+/// The unit is optional, defaulting to bits per second.
+/// ```
+/// use engineering_repr::DataRate;
+/// use std::str::FromStr as _;
+/// let rate = DataRate::<u64>::from_str("100Mbps").unwrap();
+/// assert_eq!(rate.to_string(), "100Mbit/s");
+/// assert_eq!(DataRate::<u64>::from_str("12.5MB/s").unwrap().to_string(), "100Mbit/s");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DataRate<T: EQSupported<T>>(pub EngineeringQuantity<T>);
+
+/// A wrapper type which displays a [`ByteSize`] or [`DataRate`] with IEC (binary) prefixes
+/// such as `Ki` (1024) or `Mi` (1024^2). It implements [`Display`].
+///
+/// Any fraction of a number of 1024 or more is truncated.
+/// Quantities too large to convert are written with SI prefixes instead.
+///
+/// This type may be conveniently created by [`ByteSize::binary_with_precision()`].
+#[derive(Copy, Clone, Debug)]
+pub struct BinaryDisplayAdapter<T: EQSupported<T>> {
+    /// The value to be displayed
+    pub value: EngineeringQuantity<T>,
+    /// The precision at which to display, or 0 to work it out losslessly
+    pub max_significant_figures: usize,
+    /// Always emit the precision requested, even any unnecessary untrailing zeroes after the decimal point.
+    pub strict: bool,
+    /// The unit to write after the number and its prefix
+    pub unit: &'static str,
+}
+
+/// Multiplies a quantity by `factor`, `times` times, reporting overflow
+fn scale<T: EQSupported<T>>(
+    mut value: EngineeringQuantity<T>,
+    factor: u16,
+    times: usize,
+) -> Result<EngineeringQuantity<T>, Error> {
+    let factor = T::from_u16(factor).ok_or(Error::Overflow)?;
+    for _ in 0..times {
+        let significand = value
+            .significand
+            .checked_mul(&factor)
+            .ok_or(Error::Overflow)?;
+        // Normalising as we go stops a fractional significand from overflowing needlessly
+        value = EngineeringQuantity {
+            significant_figures: value.significant_figures,
+            ..EngineeringQuantity::from_raw(significand, value.exponent)?.normalise()
+        };
+    }
+    Ok(value)
+}
+
+/// Joins a trailing prefix to its number, so `1.5 G` becomes `1.5G`
+fn join_prefix(s: &str) -> Cow<'_, str> {
+    match s.rsplit_once(char::is_whitespace) {
+        Some((number, prefix)) if !prefix.is_empty() && prefix.chars().all(char::is_alphabetic) => {
+            Cow::Owned(format!("{}{prefix}", number.trim_end()))
+        }
+        _ => Cow::Borrowed(s),
+    }
+}
+
+/// Parses a number with an optional SI (k and above) or IEC prefix
+fn parse_prefixed<T: EQSupported<T> + FromStr>(s: &str) -> Result<EngineeringQuantity<T>, Error> {
+    let s = s.trim_end();
+    let iec = IEC_PREFIXES
+        .iter()
+        .zip(1..)
+        .find_map(|(p, power)| s.strip_suffix(p).map(|number| (number, power)));
+    if let Some((number, power)) = iec {
+        let parser = Parser {
+            prefixes: PrefixSet::empty().with(0),
+            ..Default::default()
+        };
+        return scale(parser.parse(number.trim_end())?, 1024, power);
+    }
+    let parser = Parser {
+        prefixes: PrefixSet::range(0, 10),
+        ..Default::default()
+    };
+    parser.parse(&join_prefix(s))
+}
+
+/// Splits a quantity into a string of decimal digits and a power of ten, in units of the largest IEC prefix
+/// which does not exceed it, returning the prefix.
+///
+/// Any fraction is truncated, unless the quantity is less than 1024.
+/// Returns None if the integer part exceeds a u128.
+pub(crate) fn binary_digits<T: EQSupported<T>>(
+    value: &EngineeringQuantity<T>,
+) -> Option<(String, i32, &'static str)> {
+    let (_, mut digits) = sign_and_digits(&value.significand);
+    let pow10 = 3 * i32::from(value.exponent);
+    let mut integer = digits.clone();
+    if pow10 >= 0 {
+        integer.push_str(&"0".repeat(pow10.unsigned_abs() as usize));
+    } else {
+        integer.truncate(integer.len().saturating_sub(pow10.unsigned_abs() as usize));
+    }
+    let whole: u128 = if integer.is_empty() {
+        0
+    } else {
+        integer.parse().ok()?
+    };
+    let Some(power) = (1..=IEC_PREFIXES.len())
+        .rev()
+        .find(|p| whole >> (10 * p) != 0)
+    else {
+        return Some((digits, pow10, ""));
+    };
+    // Divide by 1024^power, which is exact in decimal within 10 * power places
+    let shift = 10 * power;
+    let mask = (1u128 << shift) - 1;
+    digits = (whole >> shift).to_string();
+    let mut remainder = whole & mask;
+    let mut places = 0;
+    while remainder != 0 {
+        remainder *= 10;
+        digits.push(char::from(
+            b'0' + u8::try_from(remainder >> shift).unwrap_or(0),
+        ));
+        remainder &= mask;
+        places += 1;
+    }
+    Some((digits, -places, IEC_PREFIXES[power - 1]))
+}
+
+impl<T: EQSupported<T> + FromStr> FromStr for ByteSize<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_end();
+        parse_prefixed(s.strip_suffix('B').unwrap_or(s)).map(Self)
+    }
+}
+
+impl<T: EQSupported<T> + FromStr> FromStr for DataRate<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_end();
+        for (unit, bits) in [
+            ("bit/s", 1),
+            ("b/s", 1),
+            ("bps", 1),
+            ("bit", 1),
+            ("B/s", 8),
+            ("Bps", 8),
+        ] {
+            if let Some(number) = s.strip_suffix(unit) {
+                return scale(parse_prefixed(number)?, bits, 1).map(Self);
+            }
+        }
+        parse_prefixed(s).map(Self)
+    }
+}
+
+macro_rules! impl_unit {
+    {$($t:ident $unit:literal),+} => {$(
+        impl<T: EQSupported<T>> $t<T> {
+            /// Creates a [`DisplayAdapter`] for this object with SI prefixes, with the given precision.
+            #[must_use]
+            pub fn with_precision(&self, max_significant_figures: usize) -> DisplayAdapter<T> {
+                self.0
+                    .with_precision(max_significant_figures)
+                    .with_prefixes(PrefixSet::range(0, 10))
+                    .with_unit($unit)
+            }
+            /// Creates a [`BinaryDisplayAdapter`] for this object, with the given precision.
+            #[must_use]
+            pub fn binary_with_precision(&self, max_significant_figures: usize) -> BinaryDisplayAdapter<T> {
+                BinaryDisplayAdapter {
+                    value: self.0.clone(),
+                    max_significant_figures,
+                    strict: false,
+                    unit: $unit,
+                }
+            }
+        }
+
+        impl<T: EQSupported<T>> Display for $t<T> {
+            /// Outputs to 3 significant figures, with SI prefixes
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.with_precision(3).fmt(f)
+            }
+        }
+
+        impl<T: EQSupported<T>> From<EngineeringQuantity<T>> for $t<T> {
+            fn from(value: EngineeringQuantity<T>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: EQSupported<T>> PartialEq for $t<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T: EQSupported<T>> Eq for $t<T> {}
+
+        impl<T: EQSupported<T>> PartialOrd for $t<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: EQSupported<T>> Ord for $t<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+    )+}
+}
+
+impl_unit!(ByteSize "B", DataRate "bit/s");
+
+impl<T: EQSupported<T>> Display for BinaryDisplayAdapter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let style = Style {
+            max_significant_figures: self.max_significant_figures,
+            strict: self.strict,
+            ..Style::default()
+        };
+        let (negative, digits) = sign_and_digits(&self.value.significand);
+        if let Some((digits, pow10, iec)) = binary_digits(&self.value) {
+            Style {
+                prefixes: PrefixSet::empty().with(0),
+                ..style
+            }
+            .write(f, negative, digits, pow10)?;
+            return write!(f, "{iec}{}", self.unit);
+        }
+        Style {
+            prefixes: PrefixSet::range(0, 10),
+            unit: self.unit,
+            ..style
+        }
+        .write(f, negative, digits, 3 * i32::from(self.value.exponent))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::{ByteSize, DataRate};
+    use crate::{EngineeringQuantity as EQ, Error};
+
+    #[test]
+    fn byte_sizes() {
+        for (s, bytes) in [
+            ("1.5GB", 1_500_000_000u64),
+            ("1.5 GB", 1_500_000_000),
+            ("4KiB", 4096),
+            ("4 KiB", 4096),
+            ("1.5KiB", 1536),
+            ("1.5MiB", 1_572_864),
+            ("1k5B", 1500),
+            ("12", 12),
+            ("12B", 12),
+            ("15EiB", 15 * (1 << 60)),
+            ("0.5KiB", 512),
+        ] {
+            let size = ByteSize::<u64>::from_str(s).unwrap();
+            assert_eq!(u64::from(size.0), bytes, "{s}");
+        }
+        for (s, err) in [
+            ("16EiB", Error::Overflow),
+            ("19EB", Error::Overflow),
+            ("1.5mB", Error::DisallowedPrefix("m")),
+            ("1.5kKiB", Error::DisallowedPrefix("k")),
+            ("1.5XB", Error::ParseError),
+        ] {
+            assert_eq!(ByteSize::<u64>::from_str(s).unwrap_err(), err, "{s}");
+        }
+        let big = ByteSize::<u128>::from_str("16EiB").unwrap();
+        assert_eq!(u128::from(big.0), 1 << 64);
+    }
+
+    #[test]
+    fn byte_size_output() {
+        for (bytes, si, binary) in [
+            (0u64, "0B", "0B"),
+            (999, "999B", "999B"),
+            (1000, "1kB", "1000B"),
+            (1024, "1.02kB", "1KiB"),
+            (1536, "1.53kB", "1.5KiB"),
+            (1_500_000_000, "1.5GB", "1.39GiB"),
+            (u64::MAX, "18.4EB", "15.9EiB"),
+        ] {
+            let size = ByteSize(EQ::<u64>::from(bytes));
+            assert_eq!(size.to_string(), si);
+            assert_eq!(size.binary_with_precision(3).to_string(), binary);
+        }
+        let size = ByteSize::<u64>::from_str("1.5MiB").unwrap();
+        assert_eq!(size.binary_with_precision(0).to_string(), "1.5MiB");
+        let mut strict = size.binary_with_precision(3);
+        strict.strict = true;
+        assert_eq!(strict.to_string(), "1.50MiB");
+        let size = ByteSize::<u128>::from_str("1QiB").unwrap();
+        assert_eq!(size.binary_with_precision(3).to_string(), "1QiB");
+    }
+
+    #[test]
+    fn data_rates() {
+        for (s, bits) in [
+            ("100Mbit/s", 100_000_000u64),
+            ("100 Mbit/s", 100_000_000),
+            ("100Mb/s", 100_000_000),
+            ("100Mbps", 100_000_000),
+            ("100Mbit", 100_000_000),
+            ("100 Mbit", 100_000_000),
+            ("100M", 100_000_000),
+            ("12.5MB/s", 100_000_000),
+            ("12.5MBps", 100_000_000),
+            ("1Gibit/s", 1 << 30),
+            ("1Gibit", 1 << 30),
+            ("1KiB/s", 8192),
+        ] {
+            let rate = DataRate::<u64>::from_str(s).unwrap();
+            assert_eq!(u64::from(rate.0), bits, "{s}");
+        }
+        assert_eq!(
+            DataRate::<u64>::from_str("3EB/s").unwrap_err(),
+            Error::Overflow
+        );
+        let rate = DataRate::<u64>::from_str("12.5MB/s").unwrap();
+        assert_eq!(rate.to_string(), "100Mbit/s");
+        assert_eq!(rate.binary_with_precision(3).to_string(), "95.3Mibit/s");
+        assert!(rate > DataRate::from_str("99Mbps").unwrap());
+        assert_eq!(rate, DataRate::from_str("100Mbps").unwrap());
+    }
+}
@@ -0,0 +1,340 @@
+//! Conversions between quantities and decibels
+
+use std::str::FromStr;
+
+use crate::string::{from_digits, parse_decimal, strip_suffix_ignore_case};
+use crate::{DisplayAdapter, EQSupported, EngineeringQuantity, Error, PrefixSet};
+
+/// The kind of quantity a decibel scale measures
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecibelKind {
+    /// A power quantity, such as watts: 10 dB is a factor of 10
+    Power,
+    /// A root-power (field) quantity, such as volts: 20 dB is a factor of 10
+    RootPower,
+}
+
+impl DecibelKind {
+    /// The number of decibels in a factor of 10
+    fn per_decade(self) -> f64 {
+        match self {
+            DecibelKind::Power => 10.0,
+            DecibelKind::RootPower => 20.0,
+        }
+    }
+}
+
+/// A decibel scale: a reference level, the kind of quantity, and the suffix which identifies it.
+///
+/// Levels in decibels are themselves held as [`EngineeringQuantity`], rounded to a number of decimal places.
+/// Quantities converted back from decibels are rounded to a number of significant figures.
+/// The logarithms are computed in `f64`, and the results correctly rounded from there.
+/// ```
+/// use engineering_repr::{DecibelScale, EngineeringQuantity as EQ};
+/// use std::str::FromStr as _;
+/// let dbm = DecibelScale::dbm();
+/// let level = dbm.to_decibels(&EQ::<i64>::from_str("10m").unwrap(), 2).unwrap();
+/// assert_eq!(dbm.display(&level).to_string(), "10dBm");
+/// let level = dbm.parse::<i64>("-30dBm").unwrap();
+/// assert_eq!(dbm.from_decibels(&level, 3).unwrap().to_string(), "1μ");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecibelScale {
+    /// The quantity which is 0 dB on this scale
+    pub reference: EngineeringQuantity<i64>,
+    /// The kind of quantity
+    pub kind: DecibelKind,
+    /// The suffix written after the level, for example `dBm`
+    pub suffix: &'static str,
+}
+
+impl DecibelScale {
+    /// Constructor
+    #[must_use]
+    pub fn new(
+        reference: EngineeringQuantity<i64>,
+        kind: DecibelKind,
+        suffix: &'static str,
+    ) -> Self {
+        Self {
+            reference,
+            kind,
+            suffix,
+        }
+    }
+    /// Power relative to 1 milliwatt
+    #[must_use]
+    pub fn dbm() -> Self {
+        Self::new(
+            EngineeringQuantity::from_raw_unchecked(1, -1),
+            DecibelKind::Power,
+            "dBm",
+        )
+    }
+    /// Power relative to 1 watt
+    #[must_use]
+    pub fn dbw() -> Self {
+        Self::new(EngineeringQuantity::from(1), DecibelKind::Power, "dBW")
+    }
+    /// Voltage relative to 1 volt
+    #[must_use]
+    pub fn dbv() -> Self {
+        Self::new(EngineeringQuantity::from(1), DecibelKind::RootPower, "dBV")
+    }
+    /// A ratio of two powers
+    #[must_use]
+    pub fn power_ratio() -> Self {
+        Self::new(EngineeringQuantity::from(1), DecibelKind::Power, "dB")
+    }
+    /// A ratio of two root-power quantities, such as voltages
+    #[must_use]
+    pub fn root_power_ratio() -> Self {
+        Self::new(EngineeringQuantity::from(1), DecibelKind::RootPower, "dB")
+    }
+
+    /// Converts a quantity to a level in decibels on this scale, rounded to the given number of decimal places.
+    ///
+    /// The quantity must be positive.
+    /// ```
+    /// use engineering_repr::{DecibelScale, EngineeringQuantity as EQ};
+    /// let gain = DecibelScale::root_power_ratio().to_decibels(&EQ::<i32>::from(2), 2).unwrap();
+    /// assert_eq!(gain.with_precision(0).to_string(), "6.02");
+    /// ```
+    pub fn to_decibels<T: EQSupported<T> + FromStr>(
+        &self,
+        quantity: &EngineeringQuantity<T>,
+        decimal_places: usize,
+    ) -> Result<EngineeringQuantity<T>, Error> {
+        let level = self.kind.per_decade() * (log10(quantity.clone())? - log10(self.reference)?);
+        from_f64_places(level, decimal_places)
+    }
+
+    /// Converts a level in decibels on this scale to a quantity, rounded to the given number of significant figures.
+    /// ```
+    /// use engineering_repr::{DecibelScale, EngineeringQuantity as EQ};
+    /// let dbm = DecibelScale::dbm();
+    /// let q = dbm.from_decibels(&EQ::<i64>::from(3), 3).unwrap();
+    /// assert_eq!(q.with_original_precision().to_string(), "2.00m");
+    /// ```
+    pub fn from_decibels<T: EQSupported<T> + FromStr>(
+        &self,
+        level: &EngineeringQuantity<T>,
+        significant_figures: usize,
+    ) -> Result<EngineeringQuantity<T>, Error> {
+        let significand = level.significand.to_f64().ok_or(Error::Overflow)?;
+        // Divide rather than multiply by a negative power, which is inexact, so the level is rounded only once
+        let scale = 10f64.powi(3 * i32::from(level.exponent.unsigned_abs()));
+        let level = if level.exponent < 0 {
+            significand / scale
+        } else {
+            significand * scale
+        };
+        let log = level / self.kind.per_decade() + log10(self.reference)?;
+        if !log.is_finite() {
+            return Err(Error::Overflow);
+        }
+        // Split into a power of ten and a mantissa from 1 to 10, so that exact powers of ten stay exact
+        let pow10 = log.floor();
+        if pow10.abs() > 1000.0 {
+            return Err(if pow10 < 0.0 {
+                Error::Underflow
+            } else {
+                Error::Overflow
+            });
+        }
+        let mantissa = 10f64.powf(log - pow10);
+        #[allow(clippy::cast_possible_truncation)]
+        EngineeringQuantity::from_f64_rounded(mantissa, significant_figures)?
+            .scale_pow10(pow10 as i32)
+    }
+
+    /// Parses a level in decibels on this scale, such as `-30dBm`.
+    ///
+    /// The suffix is optional, and case insensitive. SI prefixes are not accepted.
+    pub fn parse<T: EQSupported<T> + FromStr>(
+        &self,
+        s: &str,
+    ) -> Result<EngineeringQuantity<T>, Error> {
+        let s = s.trim();
+        let number = strip_suffix_ignore_case(s, self.suffix).map_or(s, str::trim_end);
+        let parsed = parse_decimal(number, false);
+        if !parsed.is_plain_number() {
+            return Err(Error::ParseError);
+        }
+        from_digits(parsed.digits, parsed.pow10)
+    }
+
+    /// Creates a [`DisplayAdapter`] for a level in decibels on this scale, such as `-30dBm`.
+    ///
+    /// The level is written in full, without SI prefixes.
+    #[must_use]
+    pub fn display<T: EQSupported<T>>(&self, level: &EngineeringQuantity<T>) -> DisplayAdapter<T> {
+        level
+            .with_precision(0)
+            .with_prefixes(PrefixSet::empty().with(0))
+            .with_unit(self.suffix)
+    }
+}
+
+/// Base-10 logarithm of a positive quantity.
+///
+/// The exponent is added exactly, so that exact powers of ten have exact logarithms.
+fn log10<T: EQSupported<T>>(quantity: EngineeringQuantity<T>) -> Result<f64, Error> {
+    let quantity = quantity.normalise();
+    let significand = quantity.significand.to_f64().ok_or(Error::Overflow)?;
+    if significand <= 0.0 {
+        return Err(Error::InvalidArgument);
+    }
+    if significand.is_infinite() {
+        return Err(Error::Overflow);
+    }
+    Ok(significand.log10() + 3.0 * f64::from(quantity.exponent))
+}
+
+/// Converts a float to a quantity, correctly rounded to the given number of decimal places
+fn from_f64_places<T: EQSupported<T> + FromStr>(
+    value: f64,
+    decimal_places: usize,
+) -> Result<EngineeringQuantity<T>, Error> {
+    if value.is_nan() {
+        return Err(Error::InvalidArgument);
+    }
+    if value.is_infinite() {
+        return Err(if value < 0.0 {
+            Error::Underflow
+        } else {
+            Error::Overflow
+        });
+    }
+    let s = format!("{value:.decimal_places$}");
+    let (int, frac) = s.split_once('.').unwrap_or((&s, ""));
+    let frac_len = i32::try_from(frac.len()).map_err(|_| Error::InvalidArgument)?;
+    let digits = format!("{int}{frac}");
+    // Rounding may leave a negative zero, which an unsigned type cannot parse
+    let digits = match digits.strip_prefix('-') {
+        Some(magnitude) if magnitude.bytes().all(|b| b == b'0') => magnitude.to_string(),
+        _ => digits,
+    };
+    from_digits(digits, -frac_len).map_err(|e| {
+        if e == Error::ParseError && value < 0.0 {
+            Error::Underflow
+        } else {
+            e
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::{DecibelKind, DecibelScale};
+    use crate::{EngineeringQuantity as EQ, Error};
+
+    #[test]
+    fn to_decibels() {
+        let dbm = DecibelScale::dbm();
+        for (q, level) in [
+            ("10m", "10dBm"),
+            ("1m", "0dBm"),
+            ("1μ", "-30dBm"),
+            ("1", "30dBm"),
+            ("2m", "3.01dBm"),
+            ("500μ", "-3.01dBm"),
+        ] {
+            let q = EQ::<i64>::from_str(q).unwrap();
+            let db = dbm.to_decibels(&q, 2).unwrap();
+            assert_eq!(dbm.display(&db).to_string(), level);
+        }
+        let dbv = DecibelScale::dbv();
+        let db = dbv.to_decibels(&EQ::<i64>::from(10), 1).unwrap();
+        assert_eq!(dbv.display(&db).to_string(), "20dBV");
+        let db = dbv
+            .to_decibels(&EQ::<i64>::from_str("1m").unwrap(), 1)
+            .unwrap();
+        assert_eq!(dbv.display(&db).to_string(), "-60dBV");
+
+        assert_eq!(
+            dbm.to_decibels(&EQ::<i64>::from(0), 2),
+            Err(Error::InvalidArgument)
+        );
+        assert_eq!(
+            dbm.to_decibels(&EQ::<i64>::from(-1), 2),
+            Err(Error::InvalidArgument)
+        );
+        // Negative levels cannot be stored in an unsigned type
+        assert_eq!(
+            dbm.to_decibels(&EQ::<u32>::from_str("1μ").unwrap(), 0),
+            Err(Error::Underflow)
+        );
+        // Rounding to a negative zero is still zero
+        let db = DecibelScale::power_ratio()
+            .to_decibels(&EQ::<u64>::from_str("999.999m").unwrap(), 2)
+            .unwrap();
+        assert!(db.is_zero());
+    }
+
+    #[test]
+    fn from_decibels() {
+        let dbm = DecibelScale::dbm();
+        for (level, q) in [
+            ("-30dBm", "1μ"),
+            ("10dBm", "10m"),
+            ("0dBm", "1m"),
+            ("3dBm", "2m"),
+            ("-3dBm", "501μ"),
+            ("60dBm", "1k"),
+        ] {
+            let level = dbm.parse::<i64>(level).unwrap();
+            assert_eq!(dbm.from_decibels(&level, 3).unwrap().to_string(), q);
+        }
+        let level = dbm.parse::<i64>("3dBm").unwrap();
+        assert_eq!(
+            dbm.from_decibels(&level, 4)
+                .unwrap()
+                .with_original_precision()
+                .to_string(),
+            "1.995m"
+        );
+        // 2.034 is not exactly representable; multiplying 2034 by 0.001 would give the neighbouring float
+        let level = dbm.parse::<i128>("2.034dBm").unwrap();
+        assert_eq!(
+            dbm.from_decibels(&level, 15)
+                .unwrap()
+                .with_original_precision()
+                .to_string(),
+            "1.59734968335354m"
+        );
+        let ratio = DecibelScale::root_power_ratio();
+        let gain = ratio.parse::<i64>("6.02 dB").unwrap();
+        assert_eq!(ratio.from_decibels(&gain, 3).unwrap().to_string(), "2");
+        assert_eq!(
+            dbm.from_decibels(&EQ::<i64>::from(10_000), 3),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            dbm.from_decibels(&EQ::<i64>::from(-10_000), 3),
+            Err(Error::Underflow)
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let dbm = DecibelScale::dbm();
+        for (s, raw) in [
+            ("-30dBm", (-30, 0)),
+            ("-30 dBm", (-30, 0)),
+            ("-30DBM", (-30, 0)),
+            ("-30", (-30, 0)),
+            ("2.5dBm", (2500, -1)),
+        ] {
+            assert_eq!(dbm.parse::<i64>(s).unwrap().to_raw(), raw, "{s}");
+        }
+        for s in ["dBm", "-30dBW", "-3kdBm", "-30 dBm dBm"] {
+            assert_eq!(dbm.parse::<i64>(s), Err(Error::ParseError), "{s}");
+        }
+        let custom = DecibelScale::new(EQ::from_str("600").unwrap(), DecibelKind::Power, "dB600");
+        let db = custom.to_decibels(&EQ::<i64>::from(6000), 1).unwrap();
+        assert_eq!(custom.display(&db).to_string(), "10dB600");
+    }
+}
@@ -0,0 +1,521 @@
+//! Quantities with a decimal (powers of ten) exponent
+
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use num_rational::Ratio;
+use num_traits::checked_pow;
+
+use crate::string::{parse_decimal, pow10_to_exponent, sign_and_digits, ParsedDecimal, Style};
+use crate::{EQSupported, EngineeringQuantity, Error};
+
+/// A sibling of [`EngineeringQuantity`] which stores its exponent in powers of ten.
+///
+/// An [`EngineeringQuantity`] stores its exponent in powers of 1000, so trailing zeroes
+/// inflate the significand: 0.5 is stored as 500m, and 1.5Q needs an `i128`.
+/// This type stores 1.5Q as (15, 29), which fits comfortably into a `u32`.
+///
+/// Numbers are still parsed and displayed with SI prefixes.
+/// ```
+/// use engineering_repr::DecimalQuantity as DQ;
+/// use std::str::FromStr as _;
+/// let q = DQ::<u32>::from_str("1.5Q").unwrap();
+/// assert_eq!(q.to_raw(), (15, 29));
+/// assert_eq!(q.to_string(), "1.5Q");
+/// ```
+///
+/// # Type parameter
+/// The type parameter `T` is the underlying storage type used for the significand of the number.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DecimalQuantity<T: EQSupported<T>> {
+    /// Significant bits
+    significand: T,
+    /// Decimal exponent i.e. powers of 10
+    exponent: i8,
+}
+
+/////////////////////////////////////////////////////////////////////////
+// BASICS
+
+impl<T: EQSupported<T>> DecimalQuantity<T> {
+    /// Raw constructor from component parts
+    ///
+    /// Unlike [`EngineeringQuantity::from_raw()`], this cannot fail, as there is no requirement
+    /// for the number to fit into the storage type `T`.
+    #[must_use]
+    pub fn from_raw(significand: T, exponent: i8) -> Self {
+        Self {
+            significand,
+            exponent,
+        }
+    }
+    /// Raw accessor to retrieve the component parts
+    #[must_use]
+    pub fn to_raw(self) -> (T, i8) {
+        (self.significand, self.exponent)
+    }
+
+    /// Scales the number to remove any unnecessary trailing zeroes.
+    ///
+    /// Zero is always normalised to (0, 0).
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// assert_eq!(DQ::from_raw(1500u32, 0).normalise().to_raw(), (15, 2));
+    /// assert_eq!(DQ::from_raw(0u32, 7).normalise().to_raw(), (0, 0));
+    /// ```
+    #[must_use]
+    pub fn normalise(self) -> Self {
+        if self.significand == T::ZERO {
+            return Self::from_raw(T::ZERO, 0);
+        }
+        let ten = ten::<T>();
+        let mut working = self;
+        loop {
+            let (div, rem) = working.significand.div_rem(&ten);
+            if working.exponent == i8::MAX || rem != T::ZERO {
+                break;
+            }
+            working.significand = div;
+            working.exponent += 1;
+        }
+        working
+    }
+}
+
+/// Internal: the number ten, in the storage type
+fn ten<T: EQSupported<T>>() -> T {
+    // Every supported type can hold 10, so this can't fail
+    T::from_u8(10).unwrap_or(T::ONE)
+}
+
+// Comparisons
+
+impl<T: EQSupported<T>> PartialEq for DecimalQuantity<T> {
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// assert_eq!(DQ::from_raw(15u32, 2), DQ::from_raw(1500, 0));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: EQSupported<T>> Eq for DecimalQuantity<T> {}
+
+impl<T: EQSupported<T>> PartialOrd for DecimalQuantity<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: EQSupported<T>> Ord for DecimalQuantity<T> {
+    /// Comparison is performed on the decimal digits, so it cannot overflow.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// use assertables::assert_lt;
+    /// assert_lt!(DQ::from_raw(15u32, 29), DQ::from_raw(2, 30));
+    /// assert_lt!(DQ::from_raw(-2i32, 30), DQ::from_raw(-15, 29));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.clone().normalise(), other.clone().normalise());
+        let sign = |v: &T| v.cmp(&T::ZERO);
+        let signs = sign(&a.significand).cmp(&sign(&b.significand));
+        if signs != Ordering::Equal || a.significand == T::ZERO {
+            return signs;
+        }
+        // Same sign, both non-zero. Compare magnitudes: first by the position of the
+        // most significant digit, then digit by digit. There are no trailing zeroes after normalisation.
+        let ((negative, da), (_, db)) = (
+            sign_and_digits(&a.significand),
+            sign_and_digits(&b.significand),
+        );
+        #[allow(clippy::cast_possible_wrap)]
+        let msd = |digits: &str, exponent: i8| digits.len() as isize + isize::from(exponent);
+        let magnitude = msd(&da, a.exponent)
+            .cmp(&msd(&db, b.exponent))
+            .then_with(|| da.cmp(&db));
+        if negative {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// CONVERSIONS
+
+impl<T: EQSupported<T>, U: EQSupported<U>> From<T> for DecimalQuantity<U>
+where
+    U: From<T>,
+{
+    /// Integers can always be promoted on conversion to [`DecimalQuantity`].
+    /// ```
+    /// let _d = engineering_repr::DecimalQuantity::<u64>::from(42u32);
+    /// ```
+    fn from(value: T) -> Self {
+        Self::from_raw(value.into(), 0)
+    }
+}
+
+impl<T: EQSupported<T>> TryFrom<EngineeringQuantity<T>> for DecimalQuantity<T> {
+    type Error = Error;
+
+    /// Conversion from [`EngineeringQuantity`] is precise.
+    ///
+    /// It fails if the exponent, in powers of ten, does not fit into an `i8`.
+    /// This can only happen with an unbounded storage type, such as [`num_bigint::BigInt`].
+    /// ```
+    /// use engineering_repr::{DecimalQuantity as DQ, EngineeringQuantity as EQ};
+    /// let q = EQ::from_raw(1500u32, 1).unwrap();
+    /// assert_eq!(DQ::try_from(q).unwrap().to_raw(), (1500, 3));
+    /// ```
+    fn try_from(value: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
+        let exponent = i8::try_from(3 * i16::from(value.exponent)).map_err(|_| {
+            if value.exponent < 0 {
+                Error::Underflow
+            } else {
+                Error::Overflow
+            }
+        })?;
+        Ok(Self::from_raw(value.significand, exponent))
+    }
+}
+
+impl<T: EQSupported<T>> TryFrom<DecimalQuantity<T>> for EngineeringQuantity<T> {
+    type Error = Error;
+
+    /// This conversion fails if the number cannot be represented as an [`EngineeringQuantity<T>`].
+    /// ```
+    /// use engineering_repr::{DecimalQuantity as DQ, EngineeringQuantity as EQ};
+    /// let q = EQ::try_from(DQ::from_raw(15u32, 2)).unwrap();
+    /// assert_eq!(q.to_raw(), (1500, 0));
+    /// let e = EQ::try_from(DQ::from_raw(15u32, 29)).unwrap_err(); // Overflow
+    /// ```
+    fn try_from(value: DecimalQuantity<T>) -> Result<Self, Self::Error> {
+        let pow10 = i32::from(value.exponent);
+        let scale: usize = pow10.rem_euclid(3).unsigned_abs() as usize;
+        let significand = checked_pow(ten::<T>(), scale)
+            .and_then(|factor| value.significand.checked_mul(&factor))
+            .ok_or(Error::Overflow)?;
+        EngineeringQuantity::from_raw(significand, pow10_to_exponent(pow10)?)
+    }
+}
+
+impl<T: EQSupported<T> + num_integer::Integer> TryFrom<DecimalQuantity<T>> for Ratio<T> {
+    type Error = Error;
+
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// use num_rational::Ratio;
+    /// let r = Ratio::try_from(DQ::from_raw(15i32, -1)).unwrap();
+    /// assert_eq!(r, Ratio::new(3, 2));
+    /// ```
+    fn try_from(value: DecimalQuantity<T>) -> Result<Self, Self::Error> {
+        let factor = checked_pow(ten::<T>(), value.exponent.unsigned_abs().into());
+        Ok(if value.exponent >= 0 {
+            let numerator = factor
+                .and_then(|f| value.significand.checked_mul(&f))
+                .ok_or(Error::Overflow)?;
+            Ratio::from_integer(numerator)
+        } else {
+            Ratio::new(value.significand, factor.ok_or(Error::Underflow)?)
+        })
+    }
+}
+
+impl<T: EQSupported<T> + num_integer::Integer> TryFrom<Ratio<T>> for DecimalQuantity<T> {
+    type Error = Error;
+
+    /// This is a precise conversion, which only succeeds if the denominator of the input Ratio
+    /// has no prime factors other than 2 and 5.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// use num_rational::Ratio;
+    /// let q = DQ::try_from(Ratio::new(3i32, 8)).unwrap();
+    /// assert_eq!(q.to_raw(), (375, -3));
+    /// let e = DQ::try_from(Ratio::new(1i32, 3)).unwrap_err(); // ImpreciseConversion
+    /// ```
+    fn try_from(value: Ratio<T>) -> Result<Self, Self::Error> {
+        let (num, denom) = value.into_raw();
+        let (two, five) = (T::ONE + T::ONE, ten::<T>() / (T::ONE + T::ONE));
+        // Count the factors of 2 and 5 in the denominator
+        let count = |mut d: T, factor: &T| {
+            let mut n = 0usize;
+            loop {
+                let (div, rem) = d.div_rem(factor);
+                if rem != T::ZERO {
+                    break;
+                }
+                d = div;
+                n += 1;
+            }
+            (d, n)
+        };
+        let (rest, twos) = count(denom, &two);
+        let (rest, fives) = count(rest, &five);
+        if rest != T::ONE {
+            return Err(Error::ImpreciseConversion);
+        }
+        // Scale up to a power of ten
+        let scale = checked_pow(two, twos.max(fives) - twos)
+            .zip(checked_pow(five, twos.max(fives) - fives))
+            .and_then(|(a, b)| a.checked_mul(&b))
+            .and_then(|s| num.checked_mul(&s))
+            .ok_or(Error::Overflow)?;
+        let exponent = i8::try_from(twos.max(fives)).map_err(|_| Error::Underflow)?;
+        Ok(Self::from_raw(scale, -exponent))
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// STRINGS
+
+impl<T: EQSupported<T> + FromStr> FromStr for DecimalQuantity<T> {
+    type Err = Error;
+
+    /// Accepts the same syntax as [`EngineeringQuantity`].
+    ///
+    /// Trailing zeroes are removed before conversion, so do not inflate the significand.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// use std::str::FromStr as _;
+    /// assert_eq!(DQ::<u16>::from_str("500m").unwrap().to_raw(), (5, -1));
+    /// assert_eq!(DQ::<u16>::from_str("1k5").unwrap().to_raw(), (15, 2));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ParsedDecimal {
+            digits: mut to_convert,
+            mut pow10,
+            ..
+        } = parse_decimal(s, false);
+        while to_convert.ends_with('0')
+            && to_convert
+                .chars()
+                .nth_back(1)
+                .is_some_and(|c| c.is_ascii_digit())
+        {
+            let _ = to_convert.pop();
+            pow10 += 1;
+        }
+        let significand = T::from_str(&to_convert).map_err(|_| Error::ParseError)?;
+        let exponent = i8::try_from(pow10).map_err(|_| {
+            if pow10 < 0 {
+                Error::Underflow
+            } else {
+                Error::Overflow
+            }
+        })?;
+        Ok(Self::from_raw(significand, exponent))
+    }
+}
+
+impl<T: EQSupported<T>> Display for DecimalQuantity<T> {
+    /// Default behaviour is the same as for [`EngineeringQuantity`]: 3 significant figures,
+    /// skip unnecessary trailing zeros, standard (not RKM) mode.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.with_precision(3).fmt(f)
+    }
+}
+
+/// A wrapper type which allows you to specify the desired output format of a [`DecimalQuantity`].
+/// It implements [`Display`].
+///
+/// The fields have the same meaning as in [`DisplayAdapter`](crate::DisplayAdapter).
+#[derive(Copy, Clone, Debug)]
+pub struct DecimalDisplayAdapter<T: EQSupported<T>> {
+    /// The value to be displayed
+    pub value: DecimalQuantity<T>,
+    /// The precision at which to display, or 0 to work it out losslessly
+    pub max_significant_figures: usize,
+    /// Specifies [RKM code](https://en.wikipedia.org/wiki/RKM_code) mode
+    pub rkm: bool,
+    /// Always emit the precision requested, even any unnecessary untrailing zeroes after the decimal point.
+    pub strict: bool,
+}
+
+impl<T: EQSupported<T>> DecimalQuantity<T> {
+    /// Creates a standard [`DecimalDisplayAdapter`] for this object, with the given precision.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// assert_eq!(DQ::from_raw(1234567u32, 0).with_precision(2).to_string(), "1.2M");
+    /// ```
+    #[must_use]
+    pub fn with_precision(&self, max_significant_figures: usize) -> DecimalDisplayAdapter<T> {
+        DecimalDisplayAdapter {
+            value: self.clone(),
+            max_significant_figures,
+            rkm: false,
+            strict: false,
+        }
+    }
+    /// Creates an RKM [`DecimalDisplayAdapter`] for this object, with the given precision.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// assert_eq!(DQ::from_raw(1234567u32, 0).rkm_with_precision(2).to_string(), "1M2");
+    /// ```
+    #[must_use]
+    pub fn rkm_with_precision(&self, max_significant_figures: usize) -> DecimalDisplayAdapter<T> {
+        DecimalDisplayAdapter {
+            value: self.clone(),
+            max_significant_figures,
+            rkm: true,
+            strict: false,
+        }
+    }
+    /// Creates a [`DecimalDisplayAdapter`] for this object, with strict precision.
+    /// ```
+    /// use engineering_repr::DecimalQuantity as DQ;
+    /// assert_eq!(DQ::from_raw(12u32, 2).with_strict_precision(3).to_string(), "1.20k");
+    /// ```
+    #[must_use]
+    pub fn with_strict_precision(
+        &self,
+        max_significant_figures: usize,
+    ) -> DecimalDisplayAdapter<T> {
+        DecimalDisplayAdapter {
+            value: self.clone(),
+            max_significant_figures,
+            rkm: false,
+            strict: true,
+        }
+    }
+}
+
+impl<T: EQSupported<T>> Display for DecimalDisplayAdapter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (negative, digits) = sign_and_digits(&self.value.significand);
+        Style {
+            max_significant_figures: self.max_significant_figures,
+            rkm: self.rkm,
+            strict: self.strict,
+            ..Style::default()
+        }
+        .write(f, negative, digits, self.value.exponent.into())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::DecimalQuantity as DQ;
+    use crate::EngineeringQuantity as EQ;
+    use crate::Error;
+    use num_rational::Ratio;
+
+    #[test]
+    fn from_string() {
+        for (s, sig, exp) in &[
+            ("0", 0u32, 0i8),
+            ("1", 1, 0),
+            ("10", 1, 1),
+            ("500m", 5, -1),
+            ("1.5k", 15, 2),
+            ("1k5", 15, 2),
+            ("1.00M", 1, 6),
+            ("4.7μ", 47, -7),
+            ("1.5Q", 15, 29),
+            ("4294967295Q", u32::MAX, 30),
+        ] {
+            let q = DQ::<u32>::from_str(s).unwrap();
+            assert_eq!(q.to_raw(), (*sig, *exp), "input {s}");
+        }
+        for s in &["foo", "1.2.3k", "--1", "4294967296"] {
+            let _ = DQ::<u32>::from_str(s).expect_err(s);
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        for (sig, exp, s) in &[
+            (0i32, 0i8, "0"),
+            (0, -5, "0"),
+            (1, 0, "1"),
+            (15, -1, "1.5"),
+            (5, -1, "500m"),
+            (15, 2, "1.5k"),
+            (47, -7, "4.7μ"),
+            (15, 29, "1.5Q"),
+            (-1234, 3, "-1.23M"),
+        ] {
+            let q = DQ::from_raw(*sig, *exp);
+            assert_eq!(q.to_string(), *s, "inputs {sig}, {exp}");
+        }
+        let q = DQ::from_raw(12_345i32, -4);
+        assert_eq!(q.with_precision(0).to_string(), "1.2345");
+        assert_eq!(q.rkm_with_precision(0).to_string(), "1.2345");
+        assert_eq!(
+            DQ::from_raw(12_345i32, 0).rkm_with_precision(0).to_string(),
+            "12k345"
+        );
+        assert_eq!(
+            DQ::from_raw(1, 3).with_strict_precision(4).to_string(),
+            "1.000k"
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        use assertables::{assert_gt, assert_lt};
+        assert_eq!(DQ::from_raw(1i32, 3), DQ::from_raw(1000, 0));
+        assert_eq!(DQ::from_raw(0i32, 3), DQ::from_raw(0, -3));
+        assert_lt!(DQ::from_raw(-1i32, 3), DQ::from_raw(0, 0));
+        assert_lt!(DQ::from_raw(999i32, 0), DQ::from_raw(1, 3));
+        assert_lt!(DQ::from_raw(1i32, -127), DQ::from_raw(1, 127));
+        assert_gt!(DQ::from_raw(-1i32, -127), DQ::from_raw(-1, 127));
+        assert_gt!(DQ::from_raw(2i32, 0), DQ::from_raw(15, -1));
+    }
+
+    #[test]
+    fn engineering() {
+        let d = DQ::try_from(EQ::from_raw(1234u32, -1).unwrap()).unwrap();
+        assert_eq!(d.to_raw(), (1234, -3));
+        for (sig, exp, eq_sig, eq_exp) in &[
+            (15i64, 2i8, 1500i64, 0i8),
+            (15, 3, 15, 1),
+            (5, -1, 500, -1),
+            (47, -7, 4700, -3),
+        ] {
+            let e = EQ::try_from(DQ::from_raw(*sig, *exp)).unwrap();
+            assert_eq!(e.to_raw(), (*eq_sig, *eq_exp));
+        }
+        assert_eq!(
+            EQ::try_from(DQ::from_raw(15u32, 29)).unwrap_err(),
+            Error::Overflow
+        );
+        assert_eq!(
+            EQ::try_from(DQ::from_raw(1i64, -120)).unwrap_err(),
+            Error::Underflow
+        );
+    }
+
+    #[test]
+    fn ratio() {
+        for (sig, exp, num, denom) in &[
+            (1i64, 0i8, 1i64, 1i64),
+            (15, 2, 1500, 1),
+            (15, -1, 3, 2),
+            (375, -3, 3, 8),
+            (-4, -2, -1, 25),
+        ] {
+            let r = Ratio::try_from(DQ::from_raw(*sig, *exp)).unwrap();
+            assert_eq!(r, Ratio::new(*num, *denom));
+            let d = DQ::try_from(r).unwrap();
+            assert_eq!(d, DQ::from_raw(*sig, *exp));
+        }
+        assert_eq!(
+            Ratio::<i64>::try_from(DQ::from_raw(1, 19)).unwrap_err(),
+            Error::Overflow
+        );
+        assert_eq!(
+            Ratio::<i64>::try_from(DQ::from_raw(1, -19)).unwrap_err(),
+            Error::Underflow
+        );
+        assert_eq!(
+            DQ::try_from(Ratio::new(1i64, 7)).unwrap_err(),
+            Error::ImpreciseConversion
+        );
+    }
+}
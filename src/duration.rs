@@ -0,0 +1,263 @@
+//! Conversions to and from [`std::time::Duration`]
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::string::sign_and_digits;
+use crate::{DisplayAdapter, EQSupported, EngineeringQuantity, Error, Parser};
+
+/// Nanoseconds per second
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+impl<T: EQSupported<T>> TryFrom<EngineeringQuantity<T>> for Duration {
+    type Error = Error;
+
+    /// Converts a quantity, in seconds, to a `Duration`.
+    ///
+    /// This is a precise conversion, which fails with [`Error::ImpreciseConversion`] if the quantity is not a
+    /// whole number of nanoseconds.
+    /// Negative quantities fail with [`Error::Underflow`]; quantities too large for a `Duration`, with [`Error::Overflow`].
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use std::str::FromStr as _;
+    /// use std::time::Duration;
+    /// let q = EQ::<i64>::from_str("1.5m").unwrap();
+    /// assert_eq!(Duration::try_from(q).unwrap(), Duration::from_micros(1500));
+    /// ```
+    fn try_from(value: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
+        if value.is_zero() {
+            return Ok(Duration::ZERO);
+        }
+        let (negative, mut digits) = sign_and_digits(&value.significand);
+        if negative {
+            return Err(Error::Underflow);
+        }
+        // The number of nanoseconds is digits * 10 ^ pow10
+        let pow10 = 3 * i32::from(value.exponent) + 9;
+        if pow10 < 0 {
+            // The value is not zero, so if every digit is to be dropped, some of them are significant
+            let keep = digits
+                .len()
+                .checked_sub(pow10.unsigned_abs() as usize)
+                .ok_or(Error::ImpreciseConversion)?;
+            if digits[keep..].bytes().any(|b| b != b'0') {
+                return Err(Error::ImpreciseConversion);
+            }
+            digits.truncate(keep);
+        } else {
+            // A Duration holds fewer than 10^29 nanoseconds, so limiting the zeroes does not change the outcome
+            digits.push_str(&"0".repeat(pow10.unsigned_abs().min(30) as usize));
+        }
+        let nanos = digits.parse::<u128>().map_err(|_| Error::Overflow)?;
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| Error::Overflow)?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+    }
+}
+
+impl<T: EQSupported<T>> TryFrom<Duration> for EngineeringQuantity<T> {
+    type Error = Error;
+
+    /// Converts a `Duration` to a quantity in seconds.
+    ///
+    /// This is a precise conversion, which fails with [`Error::Overflow`] if the storage type is too small.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use std::time::Duration;
+    /// let q = EQ::<i32>::try_from(Duration::from_micros(1500)).unwrap();
+    /// assert_eq!(q.to_string(), "1.5m");
+    /// ```
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let mut nanos = value.as_nanos();
+        let mut exponent = -3i8;
+        while nanos != 0 && nanos % 1000 == 0 {
+            nanos /= 1000;
+            exponent += 1;
+        }
+        if nanos == 0 {
+            exponent = 0;
+        }
+        let significand = T::from_u128(nanos).ok_or(Error::Overflow)?;
+        EngineeringQuantity::from_raw(significand, exponent)
+    }
+}
+
+impl Parser {
+    /// Parses a string into a [`Duration`], in seconds.
+    ///
+    /// The string may end with the unit `s`. The number may be separated from its prefix and unit by a space.
+    /// Without the unit, the number is still read as seconds, so `1.5m` is 1.5 milliseconds.
+    /// ```
+    /// use engineering_repr::Parser;
+    /// use std::time::Duration;
+    /// let parser = Parser::default();
+    /// assert_eq!(parser.parse_duration("1.5ms").unwrap(), Duration::from_micros(1500));
+    /// assert_eq!(parser.parse_duration("1.5 ms").unwrap(), Duration::from_micros(1500));
+    /// assert_eq!(parser.parse_duration("20µs").unwrap(), Duration::from_micros(20));
+    /// assert_eq!(parser.parse_duration("2k s").unwrap(), Duration::from_secs(2000));
+    /// assert_eq!(parser.parse_duration("30").unwrap(), Duration::from_secs(30));
+    /// ```
+    pub fn parse_duration(&self, s: &str) -> Result<Duration, Error> {
+        let s = s.trim();
+        let number = s.strip_suffix('s').map_or(s, str::trim_end);
+        // Allow a space between the number and its prefix, as in `1.5 ms`
+        let number = match number.char_indices().next_back() {
+            Some((i, c)) if !c.is_ascii_digit() && c != '.' => {
+                format!("{}{c}", number[..i].trim_end())
+            }
+            _ => number.to_string(),
+        };
+        Duration::try_from(self.parse::<i128>(&number)?)
+    }
+}
+
+/// A [`Duration`], displayed in seconds with an SI prefix and the unit `s`.
+/// ```
+/// use engineering_repr::Seconds;
+/// use std::time::Duration;
+/// assert_eq!(Seconds(Duration::from_micros(1500)).to_string(), "1.5ms");
+/// assert_eq!(Seconds(Duration::from_secs(2000)).to_string(), "2ks");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seconds(pub Duration);
+
+impl Seconds {
+    /// Creates a [`DisplayAdapter`] for this duration, with the given precision.
+    /// ```
+    /// use engineering_repr::Seconds;
+    /// use std::time::Duration;
+    /// assert_eq!(Seconds(Duration::new(1, 1)).with_precision(0).to_string(), "1.000000001s");
+    /// ```
+    #[must_use]
+    pub fn with_precision(&self, max_significant_figures: usize) -> DisplayAdapter<u128> {
+        seconds(self.0)
+            .with_precision(max_significant_figures)
+            .with_unit("s")
+    }
+    /// Creates an RKM [`DisplayAdapter`] for this duration, with the given precision.
+    /// ```
+    /// use engineering_repr::Seconds;
+    /// use std::time::Duration;
+    /// assert_eq!(Seconds(Duration::from_micros(1500)).rkm_with_precision(3).to_string(), "1m5s");
+    /// ```
+    #[must_use]
+    pub fn rkm_with_precision(&self, max_significant_figures: usize) -> DisplayAdapter<u128> {
+        seconds(self.0)
+            .rkm_with_precision(max_significant_figures)
+            .with_unit("s")
+    }
+}
+
+impl Display for Seconds {
+    /// Outputs to 3 significant figures
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.with_precision(3).fmt(f)
+    }
+}
+
+impl From<Duration> for Seconds {
+    fn from(value: Duration) -> Self {
+        Self(value)
+    }
+}
+
+/// Converts a `Duration` to seconds. This cannot fail, as a u128 holds any number of nanoseconds.
+fn seconds(duration: Duration) -> EngineeringQuantity<u128> {
+    EngineeringQuantity::try_from(duration).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+    use std::time::Duration;
+
+    use super::Seconds;
+    use crate::{EngineeringQuantity as EQ, Error, Parser};
+
+    #[test]
+    fn to_duration() {
+        for (s, d) in [
+            ("1.5m", Duration::from_micros(1500)),
+            ("20μ", Duration::from_micros(20)),
+            ("1n", Duration::from_nanos(1)),
+            ("1000p", Duration::from_nanos(1)),
+            ("0", Duration::ZERO),
+            ("0q", Duration::ZERO),
+            ("2k", Duration::from_secs(2000)),
+            ("1.000000001", Duration::new(1, 1)),
+            ("18E", Duration::from_secs(18_000_000_000_000_000_000)),
+        ] {
+            let q = EQ::<i128>::from_str(s).unwrap();
+            assert_eq!(Duration::try_from(q), Ok(d), "{s}");
+        }
+        for (s, err) in [
+            ("1p", Error::ImpreciseConversion),
+            ("1.5n", Error::ImpreciseConversion),
+            ("1q", Error::ImpreciseConversion),
+            ("-1", Error::Underflow),
+            ("19E", Error::Overflow),
+            ("1Q", Error::Overflow),
+        ] {
+            let q = EQ::<i128>::from_str(s).unwrap();
+            assert_eq!(Duration::try_from(q), Err(err), "{s}");
+        }
+    }
+
+    #[test]
+    fn from_duration() {
+        for (d, raw) in [
+            (Duration::from_micros(1500), (1500, -2)),
+            (Duration::from_secs(3), (3, 0)),
+            (Duration::from_secs(2000), (2, 1)),
+            (Duration::new(1, 1), (1_000_000_001, -3)),
+            (Duration::ZERO, (0, 0)),
+        ] {
+            let q = EQ::<i64>::try_from(d).unwrap();
+            assert_eq!(q.to_raw(), raw);
+            assert_eq!(Duration::try_from(q), Ok(d));
+        }
+        assert_eq!(
+            EQ::<i16>::try_from(Duration::new(1, 1)),
+            Err(Error::Overflow)
+        );
+        let max = EQ::<u128>::try_from(Duration::MAX).unwrap();
+        assert_eq!(Duration::try_from(max), Ok(Duration::MAX));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let parser = Parser::default();
+        for (s, d) in [
+            ("1.5ms", Duration::from_micros(1500)),
+            ("1.5m", Duration::from_micros(1500)),
+            ("20µs", Duration::from_micros(20)),
+            ("20us", Duration::from_micros(20)),
+            ("2k s", Duration::from_secs(2000)),
+            ("1.5 ms", Duration::from_micros(1500)),
+            ("250 µs", Duration::from_micros(250)),
+            ("250 µ", Duration::from_micros(250)),
+            (" 3 s ", Duration::from_secs(3)),
+            ("5s", Duration::from_secs(5)),
+            ("1m5s", Duration::from_micros(1500)),
+        ] {
+            assert_eq!(parser.parse_duration(s), Ok(d), "{s}");
+        }
+        assert_eq!(parser.parse_duration("-5s"), Err(Error::Underflow));
+        assert_eq!(parser.parse_duration("s"), Err(Error::ParseError));
+        assert_eq!(parser.parse_duration("5ss"), Err(Error::ParseError));
+
+        assert_eq!(Seconds(Duration::from_micros(1500)).to_string(), "1.5ms");
+        assert_eq!(Seconds(Duration::from_micros(20)).to_string(), "20μs");
+        assert_eq!(
+            Seconds(Duration::new(1, 1)).with_precision(0).to_string(),
+            "1.000000001s"
+        );
+        assert_eq!(Seconds(Duration::ZERO).to_string(), "0s");
+        assert_eq!(
+            Seconds(Duration::from_micros(1500))
+                .rkm_with_precision(3)
+                .to_string(),
+            "1m5s"
+        );
+    }
+}
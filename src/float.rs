@@ -1,26 +1,25 @@
 //! Conversions to [`num_rational::Ratio`] and float
 
+use std::str::FromStr;
+
 use num_rational::Ratio;
-use num_traits::checked_pow;
 
-use crate::{EQSupported, EngineeringQuantity, Error};
+use crate::string::from_digits;
+use crate::{base_pow, EQSupported, EngineeringQuantity, Error};
 
 /////////////////////////////////////////////////////////////////////////////////
 // RATIO
 
-impl<T: EQSupported<T> + num_integer::Integer + std::convert::From<EngineeringQuantity<T>>>
-    TryFrom<EngineeringQuantity<T>> for Ratio<T>
-{
+impl<T: EQSupported<T> + num_integer::Integer> TryFrom<EngineeringQuantity<T>> for Ratio<T> {
     type Error = Error;
 
     fn try_from(value: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
         Ok(if value.exponent >= 0 {
-            // it cannot have a fractional part
-            let result: T = value.into();
-            Ratio::new(Into::<T>::into(result), T::ONE)
+            // it cannot have a fractional part, but may not fit (with an 8-bit storage type)
+            Ratio::new(value.try_to_integer_exact()?, T::ONE)
         } else {
-            let denom: T = checked_pow(T::EXPONENT_BASE, value.exponent.unsigned_abs().into())
-                .ok_or(Error::Underflow)?;
+            let denom: T =
+                base_pow::<T>(value.exponent.unsigned_abs().into()).ok_or(Error::Underflow)?;
             Ratio::new(value.significand, denom)
         })
     }
@@ -38,10 +37,12 @@ where
         let (sig, exp) = if denom == T::ONE {
             (num, 0i8)
         } else {
+            // A type too small to hold 1000 cannot hold any fraction
+            let base = base_pow::<T>(1).ok_or(Error::Overflow)?;
             let mut exp = 0i8;
             // Scale away any powers of 1000
             loop {
-                let (div, rem) = denom.div_rem(&T::EXPONENT_BASE);
+                let (div, rem) = denom.div_rem(&base);
                 if div == T::ZERO || rem != T::ZERO {
                     break;
                 }
@@ -50,16 +51,16 @@ where
             }
 
             // if 1000 divides by denom precisely, we can scale up to make a precise conversion
-            let (scale, rem) = T::EXPONENT_BASE.div_rem(&denom);
+            let (scale, rem) = base.div_rem(&denom);
             if rem != T::ZERO {
                 return Err(Error::ImpreciseConversion);
             }
             // The denominator is _divided_ by scale, which means we're rounding up to the next exponent.
             // Even when the denominator is 1, this logic still works, though it might overflow so special-case it.
-            if scale == T::EXPONENT_BASE {
+            if scale == base {
                 (num, exp)
             } else {
-                (num * scale, exp - 1)
+                (num.checked_mul(&scale).ok_or(Error::Overflow)?, exp - 1)
             }
         };
         EngineeringQuantity::from_raw(sig, exp)
@@ -83,6 +84,198 @@ where
     }
 }
 
+impl<T: EQSupported<T> + FromStr> TryFrom<f64> for EngineeringQuantity<T> {
+    type Error = Error;
+
+    /// Converts a float to a quantity, exactly.
+    ///
+    /// The value converted is the shortest decimal string which represents the float (as `Display` would output),
+    /// so that `0.001` is treated as 1m even though it cannot be represented exactly in binary.
+    /// If that decimal does not fit the storage type, the conversion fails with [`Error::ImpreciseConversion`];
+    /// see [`EngineeringQuantity::from_f64_rounded()`] for a rounding conversion.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, Error};
+    /// let q = EQ::<i32>::try_from(0.0015).unwrap();
+    /// assert_eq!(q.to_raw(), (1500, -2));
+    /// assert_eq!(EQ::<i16>::try_from(1.0001), Err(Error::ImpreciseConversion));
+    /// ```
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        from_f64(value, None)
+    }
+}
+
+impl<T: EQSupported<T> + FromStr> EngineeringQuantity<T> {
+    /// Converts a float to a quantity, rounding to the given number of significant figures.
+    ///
+    /// Zero significant figures is treated as 1, and more than 17 as 17, which is enough to identify any `f64`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::<i32>::from_f64_rounded(1.0001, 3).unwrap();
+    /// assert_eq!(q.with_original_precision().to_string(), "1.00");
+    /// let q = EQ::<i16>::from_f64_rounded(1234.5, 2).unwrap();
+    /// assert_eq!(q.to_raw(), (1200, 0));
+    /// ```
+    pub fn from_f64_rounded(value: f64, significant_figures: usize) -> Result<Self, Error> {
+        from_f64(value, Some(significant_figures))
+    }
+}
+
+/// The number of significant figures which identifies any `f64` uniquely
+const MAX_F64_DIGITS: usize = 17;
+
+/// Converts a float via its decimal representation, optionally rounding to a number of significant figures
+fn from_f64<T: EQSupported<T> + FromStr>(
+    value: f64,
+    significant_figures: Option<usize>,
+) -> Result<EngineeringQuantity<T>, Error> {
+    if value.is_nan() {
+        return Err(Error::InvalidArgument);
+    }
+    if value.is_infinite() || (value < 0.0 && T::is_unsigned()) {
+        return Err(if value < 0.0 {
+            Error::Underflow
+        } else {
+            Error::Overflow
+        });
+    }
+    if value == 0.0 {
+        return EngineeringQuantity::from_raw(T::ZERO, 0);
+    }
+    // Scientific notation makes it easy to split the digits from the exponent
+    let s = match significant_figures {
+        None => format!("{value:e}"),
+        Some(n) => format!("{value:.*e}", n.clamp(1, MAX_F64_DIGITS) - 1),
+    };
+    let (mantissa, exp) = s.split_once('e').ok_or(Error::ParseError)?;
+    let exp = i32::from_str(exp).map_err(|_| Error::ParseError)?;
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let frac_len = i32::try_from(frac.len()).map_err(|_| Error::ParseError)?;
+    from_digits(format!("{int}{frac}"), exp - frac_len).map_err(|e| {
+        // If the value fits at a lower precision, it was the digits which didn't fit
+        if e == Error::ParseError
+            || (significant_figures.is_none() && from_f64::<T>(value, Some(1)).is_ok())
+        {
+            Error::ImpreciseConversion
+        } else {
+            e
+        }
+    })
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+// DECIMAL TYPES
+
+/// Converts a number of the form `digits` * 10 ^ -`scale`, as used by the decimal crates.
+///
+/// The number of significant figures is recorded, so trailing zeroes in the decimal are preserved.
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+fn from_scaled<T: EQSupported<T> + FromStr>(
+    digits: String,
+    scale: i64,
+) -> Result<EngineeringQuantity<T>, Error> {
+    let negative = digits.starts_with('-');
+    let pow10 = scale
+        .checked_neg()
+        .and_then(|p| i32::try_from(p).ok())
+        .ok_or(if scale > 0 {
+            Error::Underflow
+        } else {
+            Error::Overflow
+        })?;
+    from_digits(digits, pow10).map_err(|e| match e {
+        // The digits were valid, so the significand didn't fit
+        Error::ParseError if negative && T::is_unsigned() => Error::Underflow,
+        Error::ParseError => Error::Overflow,
+        e => e,
+    })
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<T: EQSupported<T>> TryFrom<EngineeringQuantity<T>> for rust_decimal::Decimal {
+    type Error = Error;
+
+    /// This is a precise conversion, which fails if the quantity is outside of the range of `Decimal`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use rust_decimal::Decimal;
+    /// let d = Decimal::try_from(EQ::<i32>::from_raw(1234, -1).unwrap()).unwrap();
+    /// assert_eq!(d.to_string(), "1.234");
+    /// ```
+    fn try_from(value: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
+        let (significand, exponent) = value.to_raw();
+        let significand: i128 = significand.try_into().map_err(|_| Error::Overflow)?;
+        let (mut mantissa, mut scale) = if exponent >= 0 {
+            let factor = num_traits::checked_pow(1000i128, exponent.unsigned_abs().into());
+            let mantissa = factor.and_then(|f| f.checked_mul(significand));
+            (mantissa.ok_or(Error::Overflow)?, 0)
+        } else {
+            (significand, 3 * u32::from(exponent.unsigned_abs()))
+        };
+        // Decimal has at most 28 places, but trailing zeroes in the significand may bring the scale within reach
+        while scale > 28 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        rust_decimal::Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| {
+            if scale > 0 {
+                Error::Underflow
+            } else {
+                Error::Overflow
+            }
+        })
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<T: EQSupported<T> + FromStr> TryFrom<rust_decimal::Decimal> for EngineeringQuantity<T> {
+    type Error = Error;
+
+    /// This is a precise conversion, which fails if the value does not fit into the storage type.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use rust_decimal::Decimal;
+    /// let q = EQ::<i64>::try_from(Decimal::new(1_200_000, 0)).unwrap();
+    /// assert_eq!(format!("{} USD", q), "1.2M USD");
+    /// ```
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        from_scaled(value.mantissa().to_string(), value.scale().into())
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<T: EQSupported<T>> TryFrom<EngineeringQuantity<T>> for bigdecimal::BigDecimal
+where
+    bigdecimal::num_bigint::BigInt: From<T>,
+{
+    type Error = Error;
+
+    /// This conversion always succeeds.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use bigdecimal::BigDecimal;
+    /// let d = BigDecimal::try_from(EQ::<i64>::from_raw(-47, 3).unwrap()).unwrap();
+    /// assert_eq!(d.to_string(), "-47000000000");
+    /// ```
+    fn try_from(value: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
+        let (significand, exponent) = value.to_raw();
+        Ok(bigdecimal::BigDecimal::new(
+            significand.into(),
+            -3 * i64::from(exponent),
+        ))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<T: EQSupported<T> + FromStr> TryFrom<bigdecimal::BigDecimal> for EngineeringQuantity<T> {
+    type Error = Error;
+
+    /// This is a precise conversion, which fails if the value does not fit into the storage type.
+    fn try_from(value: bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        let (digits, scale) = value.into_bigint_and_exponent();
+        from_scaled(digits.to_string(), scale)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
@@ -140,7 +333,7 @@ mod test {
             let ratio = Ratio::new(*num, *denom);
             let eq: EQ<i64> = ratio.try_into().unwrap();
             let expected = EQ::from_raw(*sig, *exp).unwrap();
-            assert_eq!(eq, expected, "inputs: {num:?}, {denom:?}",);
+            assert_eq!(eq, expected, "inputs: {num:?}, {denom:?}");
         }
     }
 
@@ -187,4 +380,140 @@ mod test {
             assert_in_epsilon!(f, (*expected) as f32, f32::EPSILON);
         }
     }
+
+    #[test]
+    fn from_f64() {
+        for (s, expected) in FLOAT_TEST_CASES {
+            let eq = EQ::<i64>::try_from(*expected).unwrap();
+            assert_eq!(eq, EQ::<i64>::from_str(s).unwrap(), "{s}");
+        }
+        for (f, raw) in &[
+            (0.0, (0, 0)),
+            (-0.0, (0, 0)),
+            (1.5, (1500, -1)),
+            (-0.001, (-1, -1)),
+            (123_456.0, (123_456, 0)),
+            (4.7e12, (4700, 3)),
+        ] {
+            let eq = EQ::<i64>::try_from(*f).unwrap();
+            assert_eq!(eq.to_raw(), *raw, "{f}");
+        }
+        for (f, err) in &[
+            (f64::NAN, Error::InvalidArgument),
+            (f64::INFINITY, Error::Overflow),
+            (f64::NEG_INFINITY, Error::Underflow),
+            (1e40, Error::Overflow),
+            (1e-40, Error::Underflow),
+            (0.1 + 0.2, Error::ImpreciseConversion),
+        ] {
+            assert_eq!(EQ::<i64>::try_from(*f), Err(*err), "{f}");
+        }
+        assert_eq!(EQ::<u32>::try_from(-1.0), Err(Error::Underflow));
+        assert_eq!(EQ::<u32>::try_from(1.0e9).unwrap().to_raw(), (1, 3));
+    }
+
+    #[test]
+    fn from_f64_rounded() {
+        for (f, n, raw, sf) in &[
+            (0.1 + 0.2, 3, (300, -1), 3),
+            (1.0001, 2, (1000, -1), 2),
+            (-123_456.0, 2, (-120, 1), 2),
+            (999.9, 3, (1000, 0), 3),
+            (0.0, 3, (0, 0), 3),
+            (2.5, 0, (2, 0), 1), // ties to even
+        ] {
+            let eq = EQ::<i32>::from_f64_rounded(*f, *n).unwrap();
+            assert_eq!(eq.to_raw(), *raw, "{f}");
+            if *f != 0.0 {
+                assert_eq!(eq.significant_figures(), Some(*sf), "{f}");
+            }
+        }
+        // An f64 never needs more than 17 significant figures
+        let eq = EQ::<i128>::from_f64_rounded(0.1, usize::MAX).unwrap();
+        assert_eq!(eq, EQ::from_f64_rounded(0.1, 17).unwrap());
+        assert_eq!(eq.significant_figures(), Some(17));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal() {
+        use rust_decimal::Decimal;
+        for (s, d) in &[
+            ("0", Decimal::ZERO),
+            ("1.5k", Decimal::new(1500, 0)),
+            ("-1.5k", Decimal::new(-1500, 0)),
+            ("1.234", Decimal::new(1234, 3)),
+            ("4.7μ", Decimal::new(47, 7)),
+            ("1.20M", Decimal::new(1_200_000, 0)),
+        ] {
+            let eq = EQ::<i64>::from_str(s).unwrap();
+            assert_eq!(Decimal::try_from(eq).unwrap(), *d, "{s}");
+            let back = EQ::<i64>::try_from(*d).unwrap();
+            assert_eq!(back, eq, "{s}");
+        }
+        // Trailing zeroes are preserved as significant figures
+        let eq = EQ::<i64>::try_from(Decimal::new(1200, 3)).unwrap();
+        assert_eq!(eq.with_original_precision().to_string(), "1.200");
+
+        // Decimal has up to 28 places and 96 bits
+        assert_eq!(
+            Decimal::try_from(EQ::<i64>::from_str("1a").unwrap()),
+            Ok(Decimal::new(1, 18))
+        );
+        assert_eq!(
+            Decimal::try_from(EQ::<i128>::from_raw(1, -10).unwrap()),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Decimal::try_from(EQ::<i128>::from_raw(1000, -10).unwrap()),
+            Ok(Decimal::new(1, 27))
+        );
+        assert_eq!(
+            Decimal::try_from(EQ::<i128>::from_raw(-100, -10).unwrap()),
+            Ok(Decimal::new(-1, 28))
+        );
+        assert_eq!(
+            Decimal::try_from(EQ::<i128>::from_raw(10, -10).unwrap()),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Decimal::try_from(EQ::<i128>::from_raw(1, 10).unwrap()),
+            Err(Error::Overflow)
+        );
+        assert_eq!(EQ::<u16>::try_from(Decimal::MAX), Err(Error::Overflow));
+        assert_eq!(
+            EQ::<u16>::try_from(Decimal::NEGATIVE_ONE),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            EQ::<i16>::try_from(Decimal::new(1, 28)),
+            Err(Error::Underflow)
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn bigdecimal() {
+        use bigdecimal::BigDecimal;
+        for (s, d) in &[
+            ("0", "0"),
+            ("1.5k", "1500"),
+            ("-1.5k", "-1500"),
+            ("1.234", "1.234"),
+            ("4.7μ", "0.0000047"),
+            ("170Q", "170000000000000000000000000000000"),
+        ] {
+            let d = BigDecimal::from_str(d).unwrap();
+            let eq = EQ::<i128>::from_str(s).unwrap();
+            assert_eq!(BigDecimal::try_from(eq).unwrap(), d, "{s}");
+            let back = EQ::<i128>::try_from(d).unwrap();
+            assert_eq!(back, eq, "{s}");
+        }
+        let huge = BigDecimal::from_str("1e100").unwrap();
+        assert_eq!(EQ::<i128>::try_from(huge), Err(Error::Overflow));
+        let tiny = BigDecimal::from_str("1e-100").unwrap();
+        assert_eq!(EQ::<i128>::try_from(tiny), Err(Error::Underflow));
+        let negative = BigDecimal::from_str("-1").unwrap();
+        assert_eq!(EQ::<u32>::try_from(negative), Err(Error::Underflow));
+    }
 }
@@ -0,0 +1,363 @@
+//! Named multipliers used in place of SI prefixes
+
+use crate::string::{parse_decimal, strip_suffix_ignore_case, ParsedDecimal};
+use crate::{Error, PrefixSet};
+
+/// A system of named multipliers, which a [`Parser`](crate::Parser) or
+/// [`DisplayAdapter`](crate::DisplayAdapter) may use instead of SI prefixes.
+///
+/// The quantity is still stored as an [`EngineeringQuantity`](crate::EngineeringQuantity);
+/// only its text representation changes.
+/// Multipliers need not be powers of 1000.
+/// ```
+/// use engineering_repr::{EngineeringQuantity as EQ, Grouping, Parser};
+/// let q = EQ::<i64>::from(1_500_000);
+/// assert_eq!(q.with_precision(2).with_grouping(Grouping::ShortScale).to_string(), "1.5 million");
+/// assert_eq!(q.with_precision(2).with_grouping(Grouping::ShortScaleAbbreviated).to_string(), "1.5M");
+/// assert_eq!(q.with_precision(2).with_grouping(Grouping::Indian).to_string(), "15 lakh");
+/// assert_eq!(q.with_precision(2).with_grouping(Grouping::Japanese).to_string(), "150万");
+/// let parser = Parser { grouping: Some(Grouping::ShortScale), ..Default::default() };
+/// assert_eq!(parser.parse::<i64>("2.3 billion").unwrap(), EQ::from(2_300_000_000i64));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// Short-scale English words: thousand, million, billion, trillion, quadrillion ... nonillion.
+    ShortScale,
+    /// Short-scale abbreviations: K, M, B and T.
+    /// Larger multipliers have no common abbreviation, so are written as words.
+    ShortScaleAbbreviated,
+    /// Indian numbering: thousand (10^3), lakh (10^5) and crore (10^7).
+    ///
+    /// When parsing, the abbreviations K, L and Cr, and the spellings lakhs, lac, lacs and crores are also accepted.
+    Indian,
+    /// Japanese numbering: 万 (10^4), 億 (10^8), 兆 (10^12) and 京 (10^16).
+    ///
+    /// When parsing, the traditional form 萬 is also accepted.
+    Japanese,
+    /// Chinese numbering: 万 (10^4), 亿 (10^8) and 万亿 (10^12).
+    ///
+    /// When parsing, the traditional forms 萬 and 億 are also accepted.
+    Chinese,
+}
+
+/// A named multiplier
+struct Name {
+    /// The power of ten it represents
+    pow10: i8,
+    /// Its name
+    word: &'static str,
+    /// Its abbreviation, written directly after the number
+    abbreviation: Option<&'static str>,
+    /// Other names which are accepted when parsing
+    aliases: &'static [&'static str],
+}
+
+impl Name {
+    /// Text between the number and the name.
+    ///
+    /// Words are separated by a space; CJK characters are written directly after the number.
+    fn separator(&self) -> &'static str {
+        if self.word.is_ascii() {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    /// All of the text which is accepted when parsing
+    fn spellings(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.word)
+            .chain(self.abbreviation)
+            .chain(self.aliases.iter().copied())
+    }
+}
+
+/// Builds a table of [`Name`]s
+macro_rules! names {
+    {$($pow10:literal $word:literal $($abbreviation:literal)? $([$($alias:literal),*])?),+ $(,)?} => {
+        [$(Name {
+            pow10: $pow10,
+            word: $word,
+            abbreviation: names!(@opt $($abbreviation)?),
+            aliases: &[$($($alias),*)?],
+        }),+]
+    };
+    (@opt) => { None };
+    (@opt $a:literal) => { Some($a) };
+}
+
+/// Short-scale multipliers, in ascending order
+static SHORT_SCALE: [Name; 10] = names! {
+    3 "thousand" "K",
+    6 "million" "M",
+    9 "billion" "B",
+    12 "trillion" "T",
+    15 "quadrillion",
+    18 "quintillion",
+    21 "sextillion",
+    24 "septillion",
+    27 "octillion",
+    30 "nonillion",
+};
+
+/// Indian multipliers, in ascending order
+static INDIAN: [Name; 3] = names! {
+    3 "thousand" "K",
+    5 "lakh" "L" ["lakhs", "lac", "lacs"],
+    7 "crore" "Cr" ["crores"],
+};
+
+/// Japanese multipliers, in ascending order
+static JAPANESE: [Name; 4] = names! {
+    4 "万" ["萬"],
+    8 "億",
+    12 "兆",
+    16 "京",
+};
+
+/// Chinese multipliers, in ascending order
+static CHINESE: [Name; 3] = names! {
+    4 "万" ["萬"],
+    8 "亿" ["億"],
+    12 "万亿" ["萬億"],
+};
+
+impl Grouping {
+    /// The multipliers in this system, in ascending order
+    fn names(self) -> &'static [Name] {
+        match self {
+            Grouping::ShortScale | Grouping::ShortScaleAbbreviated => &SHORT_SCALE,
+            Grouping::Indian => &INDIAN,
+            Grouping::Japanese => &JAPANESE,
+            Grouping::Chinese => &CHINESE,
+        }
+    }
+
+    /// Does this system prefer abbreviations for output?
+    fn abbreviated(self) -> bool {
+        matches!(self, Grouping::ShortScaleAbbreviated)
+    }
+
+    /// The powers of ten which may be output, including the absence of a multiplier
+    pub(crate) fn pow10s(self) -> PrefixSet {
+        self.names()
+            .iter()
+            .fold(PrefixSet::empty().with(0), |set, n| {
+                set.with_pow10(i32::from(n.pow10))
+            })
+    }
+
+    /// The text to write after the number for the given power of ten, if it has a name,
+    /// as (separator, name)
+    pub(crate) fn suffix(self, pow10: i32) -> Option<(&'static str, &'static str)> {
+        let name = self.names().iter().find(|n| i32::from(n.pow10) == pow10)?;
+        match name.abbreviation {
+            Some(a) if self.abbreviated() => Some(("", a)),
+            _ => Some((name.separator(), name.word)),
+        }
+    }
+
+    /// Splits a string into a (signed) string of decimal digits and a power of ten.
+    ///
+    /// Words and abbreviations are all accepted, ignoring ASCII case, with or without a space.
+    /// Where several match, the longest wins (so 万亿 is not read as 亿).
+    /// SI prefixes are not accepted.
+    pub(crate) fn parse(self, s: &str) -> Result<ParsedDecimal, Error> {
+        let trimmed = s.trim_end();
+        let found = self
+            .names()
+            .iter()
+            .flat_map(|n| n.spellings().map(move |text| (i32::from(n.pow10), text)))
+            .filter_map(|(pow10, text)| {
+                strip_suffix_ignore_case(trimmed, text).map(|number| (pow10, text.len(), number))
+            })
+            .max_by_key(|(_, len, _)| *len);
+        let (multiplier, number) = match found {
+            Some((pow10, _, number)) => (pow10, number.trim_end()),
+            None => (0, trimmed),
+        };
+        let parsed = parse_decimal(number, false);
+        if !parsed.is_plain_number() {
+            return Err(Error::ParseError);
+        }
+        Ok(ParsedDecimal {
+            pow10: parsed.pow10 + multiplier,
+            prefix: None,
+            ..parsed
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::Grouping;
+    use crate::{EngineeringQuantity as EQ, Error, Parser};
+
+    #[test]
+    fn short_scale_output() {
+        for (value, words, abbreviated) in [
+            (1_500_000i64, "1.5 million", "1.5M"),
+            (2_300_000_000, "2.3 billion", "2.3B"),
+            (999, "999", "999"),
+            (1000, "1 thousand", "1K"),
+            (-47_000_000_000_000, "-47 trillion", "-47T"),
+            (5_000_000_000_000_000, "5 quadrillion", "5 quadrillion"),
+        ] {
+            let q = EQ::<i64>::from(value);
+            assert_eq!(
+                q.with_precision(3)
+                    .with_grouping(Grouping::ShortScale)
+                    .to_string(),
+                words
+            );
+            assert_eq!(
+                q.with_precision(3)
+                    .with_grouping(Grouping::ShortScaleAbbreviated)
+                    .to_string(),
+                abbreviated
+            );
+        }
+        let q = EQ::<i64>::from(1_234_567);
+        assert_eq!(
+            q.with_strict_precision(5)
+                .with_grouping(Grouping::ShortScale)
+                .to_string(),
+            "1.2345 million"
+        );
+        assert_eq!(
+            q.with_decimal_places(1)
+                .with_grouping(Grouping::ShortScaleAbbreviated)
+                .to_string(),
+            "1.2M"
+        );
+        // There are no names for fractions
+        let q = EQ::<i64>::from_str("1.5m").unwrap();
+        assert_eq!(
+            q.with_precision(3)
+                .with_grouping(Grouping::ShortScale)
+                .to_string(),
+            "0.0015"
+        );
+    }
+
+    #[test]
+    fn short_scale_parse() {
+        let parser = Parser {
+            grouping: Some(Grouping::ShortScale),
+            ..Default::default()
+        };
+        for (s, value) in [
+            ("1.5 million", 1_500_000i64),
+            ("1.5 Million", 1_500_000),
+            ("1.5million", 1_500_000),
+            ("2.3B", 2_300_000_000),
+            ("2.3 b", 2_300_000_000),
+            ("-4K", -4000),
+            ("4k", 4000),
+            ("12", 12),
+            ("0.5 trillion", 500_000_000_000),
+        ] {
+            assert_eq!(parser.parse::<i64>(s).unwrap(), EQ::from(value), "{s}");
+        }
+        let q = parser.parse::<i64>("1.50 million").unwrap();
+        assert_eq!(q.with_original_precision().to_string(), "1.50M");
+        for s in [
+            "1.5G",
+            "1.5 milli",
+            "million",
+            "1.5 millions",
+            "1.5 million million",
+        ] {
+            assert_eq!(parser.parse::<i64>(s), Err(Error::ParseError), "{s}");
+        }
+    }
+
+    #[test]
+    fn indian_and_east_asian_output() {
+        for (value, indian, japanese, chinese) in [
+            (150_000i64, "1.5 lakh", "15万", "15万"),
+            (20_000_000, "2 crore", "2000万", "2000万"),
+            (30_000, "30 thousand", "3万", "3万"),
+            (120_000_000, "12 crore", "1.2億", "1.2亿"),
+            (999, "999", "999", "999"),
+            (-4_500_000_000_000, "-450000 crore", "-4.5兆", "-4.5万亿"),
+        ] {
+            let q = EQ::<i64>::from(value);
+            for (grouping, expected) in [
+                (Grouping::Indian, indian),
+                (Grouping::Japanese, japanese),
+                (Grouping::Chinese, chinese),
+            ] {
+                assert_eq!(
+                    q.with_precision(0).with_grouping(grouping).to_string(),
+                    expected,
+                    "{grouping:?}"
+                );
+            }
+        }
+        let q = EQ::<i64>::from(123_456);
+        assert_eq!(
+            q.with_precision(2)
+                .with_grouping(Grouping::Indian)
+                .to_string(),
+            "1.2 lakh"
+        );
+        assert_eq!(
+            q.with_strict_precision(4)
+                .with_grouping(Grouping::Japanese)
+                .to_string(),
+            "12.34万"
+        );
+        let q = EQ::<i64>::from(10_i64.pow(17));
+        assert_eq!(
+            q.with_precision(3)
+                .with_grouping(Grouping::Japanese)
+                .to_string(),
+            "10京"
+        );
+    }
+
+    #[test]
+    fn indian_and_east_asian_parse() {
+        for (grouping, s, value) in [
+            (Grouping::Indian, "1.5 lakh", 150_000i64),
+            (Grouping::Indian, "1.5 Lakhs", 150_000),
+            (Grouping::Indian, "2 crore", 20_000_000),
+            (Grouping::Indian, "2Cr", 20_000_000),
+            (Grouping::Indian, "3.5 lac", 350_000),
+            (Grouping::Indian, "4 thousand", 4000),
+            (Grouping::Japanese, "3万", 30_000),
+            (Grouping::Japanese, "3 萬", 30_000),
+            (Grouping::Japanese, "1.2億", 120_000_000),
+            (Grouping::Japanese, "-2兆", -2_000_000_000_000),
+            (Grouping::Chinese, "1.2亿", 120_000_000),
+            (Grouping::Chinese, "1.2億", 120_000_000),
+            (Grouping::Chinese, "3万亿", 3_000_000_000_000),
+            (Grouping::Chinese, "42", 42),
+        ] {
+            let parser = Parser {
+                grouping: Some(grouping),
+                ..Default::default()
+            };
+            assert_eq!(parser.parse::<i64>(s).unwrap(), EQ::from(value), "{s}");
+        }
+        let parser = Parser {
+            grouping: Some(Grouping::Japanese),
+            ..Default::default()
+        };
+        for s in ["1.5 lakh", "3亿", "万", "1.5k", "3万万"] {
+            assert_eq!(parser.parse::<i64>(s), Err(Error::ParseError), "{s}");
+        }
+        // Values land in the same representation, whatever the grouping
+        let indian = Parser {
+            grouping: Some(Grouping::Indian),
+            ..Default::default()
+        };
+        assert_eq!(
+            indian.parse::<i64>("10 lakh").unwrap(),
+            EQ::<i64>::from_str("1M").unwrap()
+        );
+    }
+}
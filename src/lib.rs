@@ -9,15 +9,50 @@
 )]
 
 use std::cmp::Ordering;
-use std::num::Saturating;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 
-use num_traits::{checked_pow, ConstOne, ConstZero, PrimInt, ToPrimitive};
+use num_traits::{
+    checked_pow, CheckedAdd, CheckedMul, CheckedSub, ConstOne, ConstZero, FromPrimitive,
+    ToPrimitive,
+};
 
 mod string;
-pub use string::{DisplayAdapter, EngineeringRepr};
+pub use string::{DisplayAdapter, EngineeringRepr, Parser};
+
+mod prefix;
+pub use prefix::{Prefix, PrefixSet};
+
+mod grouping;
+pub use grouping::Grouping;
 
 mod float;
 
+mod duration;
+pub use duration::Seconds;
+
+mod bytes;
+pub use bytes::{BinaryDisplayAdapter, ByteSize, DataRate};
+
+mod parts_per;
+pub use parts_per::{PartsPer, PartsPerDisplayAdapter};
+
+mod decibel;
+pub use decibel::{DecibelKind, DecibelScale};
+
+mod decimal;
+pub use decimal::{DecimalDisplayAdapter, DecimalQuantity};
+
+mod sweep;
+pub use sweep::{DecadeSweep, ESeries, LinearSweep};
+
+#[cfg(feature = "bigint")]
+mod bigint;
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
 #[cfg(feature = "serde")]
 mod serde_support;
 
@@ -35,15 +70,28 @@ pub struct EngineeringQuantity<T: EQSupported<T>> {
     significand: T,
     /// Engineering exponent i.e. powers of 1e3
     exponent: i8,
+    /// Number of significant figures in the string this quantity was parsed from, if any
+    significant_figures: Option<u8>,
 }
 
 /////////////////////////////////////////////////////////////////////////
 // META (SUPPORTED STORAGE TYPES)
 
 /// Marker trait indicating that a type is supported as a storage type for [`EngineeringQuantity`].
-pub trait EQSupported<T: PrimInt>:
-    PrimInt
+///
+/// This is implemented for the primitive integers and,
+/// with the `bigint` feature, for [`num_bigint::BigInt`] and [`num_bigint::BigUint`].
+pub trait EQSupported<T>:
+    Clone
+    + Ord
+    + std::fmt::Debug
     + std::fmt::Display
+    + num_integer::Integer
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + ToPrimitive
+    + FromPrimitive
     + ConstZero
     + ConstOne
     + SignHelper<T>
@@ -52,33 +100,71 @@ pub trait EQSupported<T: PrimInt>:
     + TryInto<u64>
     + TryInto<u128>
 {
-    /// Always 1000 (used internally)
-    const EXPONENT_BASE: T;
+    /// The smallest value which may be stored, if there is one (used internally)
+    fn minimum() -> Option<T>;
+    /// The largest value which may be stored, if there is one (used internally)
+    fn maximum() -> Option<T>;
+    /// Is this an unsigned type? (used internally)
+    fn is_unsigned() -> bool;
 }
 
 macro_rules! supported_types {
     {$($t:ty),+} => {$(
         impl<> EQSupported<$t> for $t {
-            const EXPONENT_BASE: $t = 1000;
+            fn minimum() -> Option<$t> {
+                Some(<$t>::MIN)
+            }
+            fn maximum() -> Option<$t> {
+                Some(<$t>::MAX)
+            }
+            fn is_unsigned() -> bool {
+                <$t>::MIN == 0
+            }
         }
     )+}
 }
 
-supported_types!(i16, i32, i64, i128, isize, u16, u32, u64, u128, usize);
+supported_types!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+mod sealed {
+    /// Storage types which can hold 1000, so every quantity they accept fits into them as an integer
+    pub trait HoldsExponentBase {}
+
+    macro_rules! holds_exponent_base {
+        {$($t:ty),+} => {$(
+            impl HoldsExponentBase for $t {}
+        )+}
+    }
+
+    holds_exponent_base!(i16, i32, i64, i128, isize, u16, u32, u64, u128, usize);
+}
+
+/// Internal: 1000 to the power of `exponent`, in the storage type, if it can be represented.
+///
+/// (1000 itself does not fit into an 8-bit type, but 1000<sup>0</sup> does.)
+pub(crate) fn base_pow<T: EQSupported<T>>(exponent: usize) -> Option<T> {
+    if exponent == 0 {
+        return Some(T::ONE);
+    }
+    checked_pow(T::from_u16(1000)?, exponent)
+}
 
-/// Signedness helper data, used by string conversions
+/// Signedness helper data, used internally
 #[derive(Debug, Clone)]
-pub struct AbsAndSign<T: PrimInt> {
+pub struct AbsAndSign<T> {
     abs: T,
     negative: bool,
 }
 
-/// Signedness helper trait, used by string conversions.
+/// Signedness helper trait, used internally.
 ///
 /// This trait exists because `abs` is, quite reasonably, only implemented
 /// for types which impl [`num_traits::Signed`].
-pub trait SignHelper<T: PrimInt> {
-    /// Unpacks a maybe-signed integer into its absolute value and sign bit
+pub trait SignHelper<T> {
+    /// Unpacks a maybe-signed integer into its absolute value and sign bit.
+    ///
+    /// The minimum value of a signed type has no positive counterpart in that type,
+    /// so its absolute value wraps around: it holds the magnitude as the unsigned counterpart would.
     fn abs_and_sign(&self) -> AbsAndSign<T>;
 }
 
@@ -96,14 +182,14 @@ macro_rules! impl_signed_helpers {
     {$($t:ty),+} => {$(
         impl<> SignHelper<$t> for $t {
             fn abs_and_sign(&self) -> AbsAndSign<$t> {
-                AbsAndSign { abs: self.abs(), negative: self.is_negative() }
+                AbsAndSign { abs: self.wrapping_abs(), negative: self.is_negative() }
             }
         }
     )+}
 }
 
-impl_unsigned_helpers!(u16, u32, u64, u128, usize);
-impl_signed_helpers!(i16, i32, i64, i128, isize);
+impl_unsigned_helpers!(u8, u16, u32, u64, u128, usize);
+impl_signed_helpers!(i8, i16, i32, i64, i128, isize);
 
 /////////////////////////////////////////////////////////////////////////
 // BASICS
@@ -113,6 +199,21 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     /// Raw constructor from component parts
     ///
     /// Construction fails if the number would overflow the storage type `T`.
+    ///
+    /// The exception is `i8` and `u8`, which cannot hold 1000. For these types only the significand is stored in `T`,
+    /// so they may carry any exponent; the value is checked when it is converted to an integer.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::from_raw(47u8, 1).unwrap();
+    /// assert_eq!(q.to_string(), "47k");
+    /// assert_eq!(q.checked_into::<u32>(), Some(47_000));
+    /// assert_eq!(q.checked_into::<u8>(), None);
+    /// ```
+    /// As the value may not fit, the infallible [`From`] conversions to integers are not available for these types:
+    /// ```compile_fail
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let _ = u16::from(EQ::from_raw(200u8, 1).unwrap());
+    /// ```
     pub fn from_raw(significand: T, exponent: i8) -> Result<Self, Error> {
         Self::from_raw_unchecked(significand, exponent).check_for_int_overflow()
     }
@@ -126,13 +227,28 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
         Self {
             significand,
             exponent,
+            significant_figures: None,
         }
     }
+    /// Accessor for the number of significant figures given in the string this quantity was parsed from.
+    ///
+    /// This is `None` if the quantity was not created by parsing a string.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use std::str::FromStr as _;
+    /// assert_eq!(EQ::<i32>::from_str("1.00M").unwrap().significant_figures(), Some(3));
+    /// assert_eq!(EQ::<i64>::from_str("0.0470").unwrap().significant_figures(), Some(3));
+    /// assert_eq!(EQ::<i32>::from(1000).significant_figures(), None);
+    /// ```
+    #[must_use]
+    pub fn significant_figures(&self) -> Option<usize> {
+        self.significant_figures.map(usize::from)
+    }
 }
 
 // Comparisons
 
-impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> PartialEq for EngineeringQuantity<T> {
+impl<T: EQSupported<T>> PartialEq for EngineeringQuantity<T> {
     /// ```
     /// use engineering_repr::EngineeringQuantity as EQ;
     /// let q1 = EQ::from_raw(42u32,0);
@@ -152,15 +268,15 @@ impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> PartialEq for Engineering
     }
 }
 
-impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> Eq for EngineeringQuantity<T> {}
+impl<T: EQSupported<T>> Eq for EngineeringQuantity<T> {}
 
-impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> PartialOrd for EngineeringQuantity<T> {
+impl<T: EQSupported<T>> PartialOrd for EngineeringQuantity<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> Ord for EngineeringQuantity<T> {
+impl<T: EQSupported<T>> Ord for EngineeringQuantity<T> {
     /// ```
     /// use engineering_repr::EngineeringQuantity as EQ;
     /// use assertables::assert_lt;
@@ -174,15 +290,23 @@ impl<T: EQSupported<T> + From<EngineeringQuantity<T>>> Ord for EngineeringQuanti
         if self.exponent == other.exponent {
             return self.significand.cmp(&other.significand);
         }
-        // Scale one to meet the other
+        // Scale the one with the larger exponent to meet the other
         let diff = self.exponent - other.exponent;
-        let diff_abs: u32 = diff.unsigned_abs().into();
+        let factor = base_pow::<T>(diff.unsigned_abs().into());
+        let compare = |larger: &T, smaller: &T| {
+            match factor.and_then(|f| larger.checked_mul(&f)) {
+                Some(scaled) => scaled.cmp(smaller),
+                // Zero is zero, whatever its exponent
+                None if *larger == T::ZERO => T::ZERO.cmp(smaller),
+                // If scaling overflows, the larger-exponent number must have the greater magnitude
+                None if *larger > T::ZERO => Ordering::Greater,
+                None => Ordering::Less,
+            }
+        };
         if diff < 0 {
-            let scaled = other.significand * T::EXPONENT_BASE.pow(diff_abs);
-            self.significand.cmp(&scaled)
+            compare(&other.significand, &self.significand).reverse()
         } else {
-            let scaled_self = self.significand * T::EXPONENT_BASE.pow(diff_abs);
-            scaled_self.cmp(&other.significand)
+            compare(&self.significand, &other.significand)
         }
     }
 }
@@ -199,8 +323,11 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     /// assert_eq!(q2.to_raw(), (42u64, 0));
     /// ```
     pub fn convert<U: EQSupported<U> + From<T>>(&self) -> EngineeringQuantity<U> {
-        let (sig, exp) = self.to_raw();
-        EngineeringQuantity::<U>::from_raw_unchecked(sig.into(), exp)
+        let (sig, exp) = self.clone().to_raw();
+        EngineeringQuantity::<U> {
+            significant_figures: self.significant_figures,
+            ..EngineeringQuantity::<U>::from_raw_unchecked(sig.into(), exp)
+        }
     }
 
     /// Fallible conversion to a different storage type.
@@ -215,20 +342,39 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     pub fn try_convert<U: EQSupported<U> + TryFrom<T>>(
         &self,
     ) -> Result<EngineeringQuantity<U>, Error> {
-        let (sig, exp) = self.to_raw();
-        EngineeringQuantity::<U>::from_raw(sig.try_into().map_err(|_| Error::Overflow)?, exp)
+        let (sig, exp) = self.clone().to_raw();
+        let result =
+            EngineeringQuantity::<U>::from_raw(sig.try_into().map_err(|_| Error::Overflow)?, exp)?;
+        Ok(EngineeringQuantity::<U> {
+            significant_figures: self.significant_figures,
+            ..result
+        })
     }
 
     /// Scales the number to remove any unnecessary groups of trailing zeroes.
+    ///
+    /// Zero is always normalised to (0, 0).
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// assert_eq!(EQ::from_raw(1_500_000u32, 0).unwrap().normalise().to_raw(), (1500, 1));
+    /// assert_eq!(EQ::from_raw(0u32, 3).unwrap().normalise().to_raw(), (0, 0));
+    /// ```
     #[must_use]
     pub fn normalise(self) -> Self {
+        if self.is_zero() {
+            return Self {
+                exponent: 0,
+                ..self
+            };
+        }
         let mut working = self;
+        let Some(base) = base_pow::<T>(1) else {
+            // No non-zero value of this type can be a multiple of 1000
+            return working;
+        };
         loop {
-            let (div, rem) = (
-                working.significand / T::EXPONENT_BASE,
-                working.significand % T::EXPONENT_BASE,
-            );
-            if rem != T::ZERO {
+            let (div, rem) = working.significand.div_rem(&base);
+            if working.exponent == i8::MAX || rem != T::ZERO {
                 break;
             }
             working.significand = div;
@@ -236,6 +382,73 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
         }
         working
     }
+
+    /// Is this quantity zero?
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.significand == T::ZERO
+    }
+
+    /// Is this quantity in its canonical form, i.e. unchanged by [`normalise`](Self::normalise)?
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// assert!(EQ::from_raw(1500u32, 1).unwrap().is_canonical());
+    /// assert!(!EQ::from_raw(1_500_000u32, 0).unwrap().is_canonical());
+    /// assert!(!EQ::from_raw(0u32, 1).unwrap().is_canonical());
+    /// ```
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        let normalised = self.clone().normalise();
+        normalised.exponent == self.exponent
+    }
+
+    /// Re-expresses the quantity with the given exponent, without changing its value.
+    ///
+    /// This fails with [`Error::ImpreciseConversion`] if the quantity is not a multiple of
+    /// 1000<sup>exponent</sup>, or [`Error::Overflow`] if the resulting significand would not fit into `T`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use engineering_repr::Error;
+    /// let q = EQ::from_raw(47i64, 1).unwrap(); // 47k
+    /// assert_eq!(q.rescale_to(-1).unwrap().to_raw(), (47_000_000, -1));
+    /// assert_eq!(q.rescale_to(2), Err(Error::ImpreciseConversion));
+    /// assert_eq!(q.rescale_to(-7), Err(Error::Overflow));
+    /// ```
+    pub fn rescale_to(&self, exponent: i8) -> Result<Self, Error> {
+        let diff = usize::from(self.exponent.abs_diff(exponent));
+        let significand = if self.is_zero() || diff == 0 {
+            self.significand.clone()
+        } else if exponent < self.exponent {
+            base_pow::<T>(diff)
+                .and_then(|factor| self.significand.checked_mul(&factor))
+                .ok_or(Error::Overflow)?
+        } else {
+            match base_pow::<T>(diff).map(|factor| self.significand.div_rem(&factor)) {
+                Some((quotient, remainder)) if remainder == T::ZERO => quotient,
+                // If the factor does not fit, any non-zero significand is too small to divide by it
+                _ => return Err(Error::ImpreciseConversion),
+            }
+        };
+        Ok(Self {
+            significant_figures: self.significant_figures,
+            ..Self::from_raw(significand, exponent)?
+        })
+    }
+
+    /// Exactly multiplies the quantity by 10^`pow10`, reporting over/underflow
+    pub(crate) fn scale_pow10(self, pow10: i32) -> Result<Self, Error> {
+        let significand = match pow10.rem_euclid(3) {
+            0 => self.significand,
+            n => T::from_u8(num_traits::pow(10, n.unsigned_abs() as usize))
+                .and_then(|scale| self.significand.checked_mul(&scale))
+                .ok_or(Error::Overflow)?,
+        };
+        let exponent = string::pow10_to_exponent(3 * i32::from(self.exponent) + pow10)?;
+        Ok(Self {
+            significant_figures: self.significant_figures,
+            ..Self::from_raw(significand, exponent)?
+        })
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////
@@ -252,20 +465,60 @@ where
     /// let _e = engineering_repr::EngineeringQuantity::<u64>::from(i);
     /// ```
     fn from(value: T) -> Self {
-        Self {
-            significand: value.into(),
-            exponent: 0,
-        }
+        Self::from_raw_unchecked(value.into(), 0)
     }
 }
 
+macro_rules! impl_from_nonzero {
+    {$($nz:ty => $t:ty),+} => {$(
+        impl<U: EQSupported<U>> From<$nz> for EngineeringQuantity<U>
+        where
+            U: From<$t>,
+        {
+            /// Non-zero integers convert in the same way as their primitive counterparts.
+            fn from(value: $nz) -> Self {
+                Self::from_raw_unchecked(value.get().into(), 0)
+            }
+        }
+
+        impl<T: EQSupported<T>> TryFrom<EngineeringQuantity<T>> for $nz
+        where
+            $t: From<T>,
+        {
+            type Error = Error;
+
+            /// Converts to the primitive counterpart, then checks that the result is non-zero.
+            ///
+            /// <div class="danger">
+            /// This is a lossy conversion, any fractional part will be truncated.
+            /// Conversion fails with [`Error::InvalidArgument`] if nothing remains.
+            /// </div>
+            ///
+            /// With an 8-bit storage type, conversion may also fail with [`Error::Overflow`] or [`Error::Underflow`].
+            fn try_from(eq: EngineeringQuantity<T>) -> Result<Self, Self::Error> {
+                <$nz>::new(eq.integer_into::<$t>()?).ok_or(Error::InvalidArgument)
+            }
+        }
+    )+}
+}
+
+impl_from_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize
+);
+
 /////////////////////////////////////////////////////////////////////////
 // CONVERSION TO INTEGER
 
 impl<T: EQSupported<T>> EngineeringQuantity<T> {
     fn check_for_int_overflow(self) -> Result<Self, Error> {
+        if base_pow::<T>(1).is_none() {
+            // A type which cannot hold 1000 stores the significand only;
+            // the value is checked when it is converted to an integer.
+            return Ok(self);
+        }
         let exp: usize = self.exponent.unsigned_abs().into();
-        let Some(factor) = checked_pow(T::EXPONENT_BASE, exp) else {
+        let Some(factor) = base_pow::<T>(exp) else {
             return Err(if self.exponent < 0 {
                 Error::Underflow
             } else {
@@ -282,7 +535,7 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
 
 macro_rules! impl_from {
     {$($t:ty),+} => {$(
-        impl<T: EQSupported<T>> From<EngineeringQuantity<T>> for $t
+        impl<T: EQSupported<T> + sealed::HoldsExponentBase> From<EngineeringQuantity<T>> for $t
         where $t: From<T>,
         {
             /// Conversion to the same storage type (or a larger type)
@@ -292,36 +545,188 @@ macro_rules! impl_from {
             /// This is a lossy conversion, any fractional part will be truncated.
             /// </div>
             ///
+            /// This conversion never panics.
+            /// Any quantity accepted by [`EngineeringQuantity::from_raw`] fits.
+            ///
+            /// It is not available for the 8-bit storage types, as they may carry an exponent which does not fit;
+            /// use [`EngineeringQuantity::checked_into`] or [`EngineeringQuantity::saturating_into`] instead.
+            ///
             /// Note that if you have [`num_traits`] in scope, you may need to rephrase the conversion as `TryInto::<T>::try_into()`.
             fn from(eq: EngineeringQuantity<T>) -> Self {
-                let abs_exp: u32 = eq.exponent.unsigned_abs().into();
-                let factor: Saturating<Self> = Saturating(T::EXPONENT_BASE.into());
-                let factor = factor.pow(abs_exp);
-                if eq.exponent > 0 {
-                    Self::from(eq.significand) * factor.0
-                } else {
-                    Self::from(eq.significand) / factor.0
-                }
+                eq.saturating_into()
             }
         }
 
     )+}
 }
 
-impl_from!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+impl_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// How to round a quantity with a fractional part to an integer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Towards negative infinity
+    Floor,
+    /// Towards positive infinity
+    Ceil,
+    /// To the nearest integer; exact halves go to the even neighbour
+    HalfEven,
+    /// Towards zero (this is what the `From` conversions do)
+    Trunc,
+}
 
 impl<T: EQSupported<T>> EngineeringQuantity<T> {
-    fn apply_factor<U: EQSupported<U>>(self, sig: U) -> Option<U> {
-        let abs_exp: usize = self.exponent.unsigned_abs().into();
-        let factor = checked_pow(U::EXPONENT_BASE, abs_exp)?;
-        Some(if self.exponent >= 0 {
-            sig * factor
+    /// Converts to an integer in the storage type, rounding any fractional part as requested.
+    ///
+    /// Conversion fails with [`Error::Overflow`] if the result does not fit into `T`.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, RoundingMode};
+    /// let q = EQ::<i32>::from_raw(1900, -1).unwrap(); // 1.9
+    /// assert_eq!(q.to_integer_rounded(RoundingMode::Ceil), Ok(2));
+    /// assert_eq!(q.to_integer_rounded(RoundingMode::Trunc), Ok(1));
+    /// let q = EQ::<i32>::from_raw(-1500, -1).unwrap(); // -1.5
+    /// assert_eq!(q.to_integer_rounded(RoundingMode::Floor), Ok(-2));
+    /// assert_eq!(q.to_integer_rounded(RoundingMode::HalfEven), Ok(-2));
+    /// ```
+    pub fn to_integer_rounded(&self, mode: RoundingMode) -> Result<T, Error> {
+        let (quotient, remainder, factor) = self.integer_parts()?;
+        if remainder == T::ZERO {
+            return Ok(quotient);
+        }
+        // The remainder is smaller in magnitude than the factor, so is never the minimum value of T
+        let AbsAndSign { abs, negative } = remainder.abs_and_sign();
+        let positive = !negative;
+        let away = match mode {
+            RoundingMode::Floor => !positive,
+            RoundingMode::Ceil => positive,
+            RoundingMode::Trunc => false,
+            RoundingMode::HalfEven => match factor {
+                Some(factor) => match abs.cmp(&(factor - abs.clone())) {
+                    Ordering::Less => false,
+                    Ordering::Equal => quotient.is_odd(),
+                    Ordering::Greater => true,
+                },
+                // The factor exceeds T, so is more than twice any remainder
+                None => false,
+            },
+        };
+        // The quotient has been divided by at least 1000, so cannot overflow here
+        Ok(match (away, positive) {
+            (false, _) => quotient,
+            (true, true) => quotient + T::ONE,
+            (true, false) => quotient - T::ONE,
+        })
+    }
+
+    /// Converts to an integer in the storage type, failing if there is a fractional part.
+    ///
+    /// Conversion fails with [`Error::ImpreciseConversion`] if there is a fractional part,
+    /// or [`Error::Overflow`] if the result does not fit into `T`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use engineering_repr::Error;
+    /// assert_eq!(EQ::<i32>::from_raw(47, 1).unwrap().try_to_integer_exact(), Ok(47_000));
+    /// assert_eq!(EQ::<i32>::from_raw(1500, -1).unwrap().try_to_integer_exact(), Err(Error::ImpreciseConversion));
+    /// ```
+    pub fn try_to_integer_exact(&self) -> Result<T, Error> {
+        match self.integer_parts()? {
+            (quotient, remainder, _) if remainder == T::ZERO => Ok(quotient),
+            _ => Err(Error::ImpreciseConversion),
+        }
+    }
+
+    /// Converts to any supported integer type, truncating any fractional part.
+    ///
+    /// Returns `None` if the result does not fit into `U`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::<u128>::from_raw(1, 10).unwrap(); // 1Q
+    /// assert_eq!(q.checked_into::<i128>(), Some(1_000_000_000_000_000_000_000_000_000_000));
+    /// assert_eq!(q.checked_into::<u64>(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_into<U: EQSupported<U> + TryFrom<T>>(&self) -> Option<U> {
+        self.integer_into().ok()
+    }
+
+    /// Converts to any supported integer type, truncating any fractional part.
+    ///
+    /// If the result does not fit into `U`, it saturates to the minimum or maximum value of `U`.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::<i64>::from_raw(-5, 4).unwrap(); // -5T
+    /// assert_eq!(q.saturating_into::<i32>(), i32::MIN);
+    /// assert_eq!(q.saturating_into::<u32>(), 0);
+    /// assert_eq!(q.saturating_into::<i64>(), -5_000_000_000_000);
+    /// ```
+    #[must_use]
+    pub fn saturating_into<U: EQSupported<U> + TryFrom<T>>(&self) -> U {
+        match self.integer_into() {
+            Ok(u) => u,
+            // Unbounded types cannot overflow, so do not reach here
+            Err(Error::Underflow) => U::minimum().unwrap_or(U::ZERO),
+            Err(_) => U::maximum().unwrap_or(U::ZERO),
+        }
+    }
+
+    /// Internal: truncating conversion to another integer type.
+    /// Fails with [`Error::Overflow`] if above the range of `U`, [`Error::Underflow`] if below it.
+    fn integer_into<U: EQSupported<U> + TryFrom<T>>(&self) -> Result<U, Error> {
+        let negative = self.significand < T::ZERO;
+        let out_of_range = if negative {
+            Error::Underflow
         } else {
-            sig / factor
+            Error::Overflow
+        };
+        if self.exponent < 0 {
+            // Dividing cannot overflow T, so do it there
+            let integer = self.to_integer_rounded(RoundingMode::Trunc)?;
+            return U::try_from(integer).map_err(|_| out_of_range);
+        }
+        let significand = U::try_from(self.significand.clone()).map_err(|_| out_of_range)?;
+        if significand == U::ZERO {
+            return Ok(significand);
+        }
+        base_pow::<U>(self.exponent.unsigned_abs().into())
+            .and_then(|factor| significand.checked_mul(&factor))
+            .ok_or(out_of_range)
+    }
+
+    /// Internal: splits into integer part and remainder, along with the divisor if it fits into `T`
+    fn integer_parts(&self) -> Result<(T, T, Option<T>), Error> {
+        if self.significand == T::ZERO {
+            return Ok((T::ZERO, T::ZERO, None));
+        }
+        let factor = base_pow::<T>(self.exponent.unsigned_abs().into());
+        if self.exponent >= 0 {
+            let integer = factor
+                .and_then(|f| self.significand.checked_mul(&f))
+                .ok_or(Error::Overflow)?;
+            return Ok((integer, T::ZERO, None));
+        }
+        Ok(match factor {
+            Some(f) => {
+                let (quotient, remainder) = self.significand.div_rem(&f);
+                (quotient, remainder, Some(f))
+            }
+            // The factor is too large to represent, so the integer part must be zero
+            None => (T::ZERO, self.significand.clone(), None),
         })
     }
 }
 
+impl<T: EQSupported<T>> EngineeringQuantity<T> {
+    fn apply_factor<U: EQSupported<U>>(&self, sig: U) -> Option<U> {
+        let abs_exp: usize = self.exponent.unsigned_abs().into();
+        let factor = base_pow::<U>(abs_exp)?;
+        if self.exponent >= 0 {
+            sig.checked_mul(&factor)
+        } else {
+            Some(sig / factor)
+        }
+    }
+}
+
 impl<T: EQSupported<T>> ToPrimitive for EngineeringQuantity<T>
 where
     f64: TryFrom<EngineeringQuantity<T>>,
@@ -338,7 +743,7 @@ where
     /// assert_eq!(e.to_i16(), None); // overflow
     /// ```
     fn to_i64(&self) -> Option<i64> {
-        let i: i64 = match self.significand.try_into() {
+        let i: i64 = match self.significand.clone().try_into() {
             Ok(ii) => ii,
             Err(_) => return None,
         };
@@ -346,7 +751,7 @@ where
     }
 
     fn to_u64(&self) -> Option<u64> {
-        let i: u64 = match self.significand.try_into() {
+        let i: u64 = match self.significand.clone().try_into() {
             Ok(ii) => ii,
             Err(_) => return None,
         };
@@ -355,7 +760,7 @@ where
 
     /// Converts `self` to an `i128`. If the value cannot be represented by an `i128`, then `None` is returned.
     fn to_i128(&self) -> Option<i128> {
-        let i: i128 = match self.significand.try_into() {
+        let i: i128 = match self.significand.clone().try_into() {
             Ok(ii) => ii,
             Err(_) => return None,
         };
@@ -364,7 +769,7 @@ where
 
     /// Converts `self` to a `u128`. If the value cannot be represented by a `u128`, then `None` is returned.
     fn to_u128(&self) -> Option<u128> {
-        let i: u128 = match self.significand.try_into() {
+        let i: u128 = match self.significand.clone().try_into() {
             Ok(ii) => ii,
             Err(_) => return None,
         };
@@ -389,7 +794,7 @@ where
     /// assert_eq!(eq.to_f64(), Some(0.123));
     /// ```
     fn to_f64(&self) -> Option<f64> {
-        f64::try_from(*self).ok()
+        f64::try_from(self.clone()).ok()
     }
 }
 
@@ -397,8 +802,11 @@ where
 // ERRORS
 
 /// Local error type returned by failing conversions
+///
+/// More variants may be added in future, so matches on this type need a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum Error {
     #[error("Numeric overflow")]
     Overflow,
@@ -408,12 +816,26 @@ pub enum Error {
     ParseError,
     #[error("The conversion could not be completed precisely")]
     ImpreciseConversion,
+    #[error("An argument was out of range")]
+    InvalidArgument,
+    #[error("{}", disallowed_prefix_message(.0))]
+    DisallowedPrefix(&'static str),
+}
+
+fn disallowed_prefix_message(prefix: &str) -> String {
+    if prefix.is_empty() {
+        "A number without an SI prefix is not permitted here".into()
+    } else {
+        format!("The SI prefix '{prefix}' is not permitted here")
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr as _;
+
     use assertables::{assert_gt, assert_lt};
 
     use super::EngineeringQuantity as EQ;
@@ -496,6 +918,33 @@ mod test {
         let q2 = q.normalise();
         assert_eq!(q, q2);
         assert_eq!(q2.to_raw(), (1, 2));
+
+        // zero terminates
+        let z = EQ::<i32>::from_raw(0, 2).unwrap();
+        assert!(z.is_zero());
+        assert!(!z.is_canonical());
+        assert_eq!(z.normalise().to_raw(), (0, 0));
+        assert!(EQ::<i32>::from(0).is_canonical());
+    }
+
+    #[test]
+    fn rescale() {
+        let q = EQ::<i64>::from_raw(-1500, 1).unwrap();
+        assert_eq!(q.rescale_to(0).unwrap().to_raw(), (-1_500_000, 0));
+        assert_eq!(q.rescale_to(2).unwrap_err(), EQErr::ImpreciseConversion);
+        assert_eq!(q.rescale_to(1).unwrap().to_raw(), (-1500, 1));
+        let q2 = q.rescale_to(0).unwrap().rescale_to(1).unwrap();
+        assert_eq!(q2.to_raw(), (-1500, 1));
+        assert_eq!(q.rescale_to(-4).unwrap_err(), EQErr::Overflow);
+        assert_eq!(EQ::<i64>::from(0).rescale_to(3).unwrap().to_raw(), (0, 3));
+        // a value beyond the range of the factor
+        assert_eq!(
+            EQ::<i64>::from_raw(1, 0)
+                .unwrap()
+                .rescale_to(7)
+                .unwrap_err(),
+            EQErr::ImpreciseConversion
+        );
     }
 
     #[test]
@@ -535,4 +984,167 @@ mod test {
         let e = EQ::from_raw(-1001, -1).unwrap();
         assert_eq!(e.to_i32(), Some(-1));
     }
+
+    #[test]
+    fn small_types() {
+        use super::RoundingMode;
+        use std::num::{NonZeroI16, NonZeroU16, NonZeroU32, NonZeroU8};
+        let e = EQ::<u8>::from_str("255").unwrap();
+        assert_eq!(e.to_raw(), (255, 0));
+        assert_eq!(e.to_string(), "255");
+        assert_eq!(e.checked_into::<u8>(), Some(255));
+        assert_eq!(EQ::<u8>::from_str("256").unwrap_err(), EQErr::ParseError);
+        assert_eq!(EQ::<i8>::from_str("-128").unwrap().to_raw(), (-128, 0));
+
+        // 1000 does not fit, so the significand carries the exponent...
+        let e = EQ::<u8>::from_raw(47, 1).unwrap();
+        assert_eq!(e.to_string(), "47k");
+        assert_eq!(EQ::<u8>::from_str("47k").unwrap(), e);
+        assert_eq!(EQ::<i8>::from_raw(-5, -1).unwrap().to_string(), "-5m");
+        // ... and the value is checked when it is converted
+        assert_eq!(e.checked_into::<u32>(), Some(47_000));
+        assert_eq!(e.checked_into::<u8>(), None);
+        assert_eq!(e.saturating_into::<u8>(), u8::MAX);
+        assert_eq!(NonZeroU8::try_from(e), Err(EQErr::Overflow));
+        assert_eq!(
+            NonZeroU16::try_from(EQ::<u8>::from_raw(200, 1).unwrap()),
+            Err(EQErr::Overflow)
+        );
+        assert_eq!(
+            NonZeroI16::try_from(EQ::<i8>::from_raw(-100, 1).unwrap()),
+            Err(EQErr::Underflow)
+        );
+        assert_eq!(NonZeroU32::try_from(e).unwrap().get(), 47_000);
+        assert_eq!(e.try_to_integer_exact(), Err(EQErr::Overflow));
+        assert_eq!(
+            e.to_integer_rounded(RoundingMode::Trunc),
+            Err(EQErr::Overflow)
+        );
+        assert_eq!(e.try_convert::<u32>().unwrap().to_raw(), (47, 1));
+        assert_eq!(e.convert::<u32>().normalise().to_raw(), (47, 1));
+        let e = EQ::<i8>::from_raw(-7, -1).unwrap();
+        assert_eq!(e.checked_into::<i16>(), Some(0));
+        assert_eq!(e.to_integer_rounded(RoundingMode::Floor), Ok(-1));
+        assert_eq!(e.try_to_integer_exact(), Err(EQErr::ImpreciseConversion));
+
+        assert!(EQ::<i8>::from(-5i8) < EQ::from(3i8));
+        assert!(EQ::<u8>::from_raw(1, 1).unwrap() > EQ::from(255u8));
+        assert!(EQ::<i8>::from_raw(-1, 1).unwrap() < EQ::from(-128i8));
+        // Zero compares equal whatever its exponent, and below any positive value
+        let zero_k = EQ::<i8>::from_raw(0, 1).unwrap();
+        assert_eq!(zero_k, EQ::from_raw(0, 0).unwrap());
+        assert_eq!(EQ::from_raw(0, -1).unwrap(), zero_k);
+        assert!(zero_k > EQ::from(-5i8));
+        assert!(EQ::<i8>::from(-5i8) < zero_k);
+        assert!(zero_k < EQ::from_raw(1, -1).unwrap());
+        assert!(EQ::<i8>::from_raw(-1, -1).unwrap() < zero_k);
+        assert_eq!(EQ::<u8>::from_raw(0, 2).unwrap(), EQ::from(0u8));
+        assert!(EQ::<u8>::from_raw(0, 2).unwrap() < EQ::from_raw(1, -2).unwrap());
+        assert_eq!(EQ::<u8>::from(10u8).normalise().to_raw(), (10, 0));
+    }
+
+    #[test]
+    fn sign_helper() {
+        use super::SignHelper as _;
+        let a = (-5i32).abs_and_sign();
+        assert_eq!((a.abs, a.negative), (5, true));
+        let a = 5u8.abs_and_sign();
+        assert_eq!((a.abs, a.negative), (5, false));
+        // The minimum has no positive counterpart, but does not panic
+        let a = i8::MIN.abs_and_sign();
+        assert_eq!((a.abs.unsigned_abs(), a.negative), (128, true));
+    }
+
+    #[test]
+    fn nonzero() {
+        use std::num::{NonZeroI32, NonZeroU32};
+        let e = EQ::<u64>::from(NonZeroU32::new(4700).unwrap());
+        assert_eq!(e.to_string(), "4.7k");
+        let n = NonZeroU32::try_from(EQ::<u32>::from_str("47k").unwrap()).unwrap();
+        assert_eq!(n.get(), 47_000);
+        assert_eq!(
+            NonZeroU32::try_from(EQ::<u32>::from(0u32)).unwrap_err(),
+            EQErr::InvalidArgument
+        );
+        // fractions are truncated first
+        assert_eq!(
+            NonZeroI32::try_from(EQ::<i32>::from_str("-500m").unwrap()).unwrap_err(),
+            EQErr::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        use super::RoundingMode::{Ceil, Floor, HalfEven, Trunc};
+        for (sig, exp, floor, ceil, half_even, trunc) in [
+            (1900, -1, 1, 2, 2, 1),
+            (-1500, -1, -2, -1, -2, -1),
+            (2500, -1, 2, 3, 2, 2),
+            (3500, -1, 3, 4, 4, 3),
+            (-2499, -1, -3, -2, -2, -2),
+            (1, -2, 0, 1, 0, 0),
+            (-1, -2, -1, 0, 0, 0),
+            (42, 1, 42_000, 42_000, 42_000, 42_000),
+        ] {
+            let e = EQ::<i64>::from_raw(sig, exp).unwrap();
+            assert_eq!(e.to_integer_rounded(Floor), Ok(floor), "{e:?}");
+            assert_eq!(e.to_integer_rounded(Ceil), Ok(ceil), "{e:?}");
+            assert_eq!(e.to_integer_rounded(HalfEven), Ok(half_even), "{e:?}");
+            assert_eq!(e.to_integer_rounded(Trunc), Ok(trunc), "{e:?}");
+        }
+    }
+
+    #[test]
+    fn integer_exact() {
+        assert_eq!(
+            EQ::<u32>::from_raw(3, 2).unwrap().try_to_integer_exact(),
+            Ok(3_000_000)
+        );
+        assert_eq!(
+            EQ::<i64>::from_raw(-4000, -1)
+                .unwrap()
+                .try_to_integer_exact(),
+            Ok(-4)
+        );
+        assert_eq!(
+            EQ::<i64>::from_raw(-4001, -1)
+                .unwrap()
+                .try_to_integer_exact(),
+            Err(EQErr::ImpreciseConversion)
+        );
+        assert_eq!(EQ::<u8>::from(0u8).try_to_integer_exact(), Ok(0));
+    }
+
+    #[test]
+    fn checked_and_saturating() {
+        let e = EQ::<i64>::from_raw(-1500, -1).unwrap();
+        assert_eq!(e.checked_into::<i8>(), Some(-1));
+        assert_eq!(e.checked_into::<u8>(), None);
+        assert_eq!(e.saturating_into::<u8>(), 0);
+
+        let e = EQ::<u128>::from_raw(300, 0).unwrap();
+        assert_eq!(e.checked_into::<u8>(), None);
+        assert_eq!(e.saturating_into::<u8>(), u8::MAX);
+        assert_eq!(e.saturating_into::<i8>(), i8::MAX);
+        assert_eq!(e.checked_into::<u16>(), Some(300));
+
+        let e = EQ::<i128>::from_raw(-1, 10).unwrap();
+        assert_eq!(e.checked_into::<i64>(), None);
+        assert_eq!(e.saturating_into::<i64>(), i64::MIN);
+        assert_eq!(e.checked_into::<i128>(), Some(-(10i128.pow(30))));
+    }
+
+    #[test]
+    fn from_does_not_panic() {
+        use num_traits::ToPrimitive as _;
+        // Not constructible via from_raw, but From must still not panic
+        let e = EQ::<u64>::from_raw_unchecked(1, 10);
+        assert_eq!(u64::from(e), u64::MAX);
+        let e = EQ::<i32>::from_raw_unchecked(-3, 4);
+        assert_eq!(i32::from(e), i32::MIN);
+        // ToPrimitive checks the scaling as well as the significand
+        let e = EQ::<i128>::from_raw(10_000_000_000, 3).unwrap();
+        assert_eq!(e.to_i64(), None);
+        assert_eq!(e.to_i128(), Some(10i128.pow(19)));
+    }
 }
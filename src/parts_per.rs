@@ -0,0 +1,284 @@
+//! Dimensionless ratios: percent, ppm, ppb and ppt
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::string::{
+    parse_decimal, sign_and_digits, strip_suffix_ignore_case, ParsedDecimal, Style,
+};
+use crate::{DisplayAdapter, EQSupported, EngineeringQuantity, Error, PrefixSet};
+
+/// A unit for a dimensionless ratio, such as a tolerance or gain error.
+///
+/// These are recognised by [`FromStr`] and [`Parser`](crate::Parser) as a suffix which scales the number,
+/// and may be output by [`DisplayAdapter::with_parts_per()`](crate::DisplayAdapter::with_parts_per).
+/// ```
+/// use engineering_repr::{EngineeringQuantity as EQ, PartsPer};
+/// use std::str::FromStr as _;
+/// let q = EQ::<i64>::from_str("1000ppm").unwrap();
+/// assert_eq!(q, EQ::from_str("1m").unwrap());
+/// assert_eq!(q.with_precision(3).with_parts_per(PartsPer::Percent).to_string(), "0.1%");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PartsPer {
+    /// Parts per hundred (%), 10^-2
+    Percent,
+    /// Parts per million (ppm), 10^-6
+    Million,
+    /// Parts per billion (ppb), 10^-9
+    Billion,
+    /// Parts per trillion (ppt), 10^-12
+    Trillion,
+}
+
+impl PartsPer {
+    /// All of the units, from largest to smallest
+    const ALL: [PartsPer; 4] = [
+        PartsPer::Percent,
+        PartsPer::Million,
+        PartsPer::Billion,
+        PartsPer::Trillion,
+    ];
+
+    /// The symbol: `%`, `ppm`, `ppb` or `ppt`
+    #[must_use]
+    pub fn symbol(self) -> &'static str {
+        match self {
+            PartsPer::Percent => "%",
+            PartsPer::Million => "ppm",
+            PartsPer::Billion => "ppb",
+            PartsPer::Trillion => "ppt",
+        }
+    }
+    /// The power of ten which one part represents
+    #[must_use]
+    pub fn pow10(self) -> i32 {
+        match self {
+            PartsPer::Percent => -2,
+            PartsPer::Million => -6,
+            PartsPer::Billion => -9,
+            PartsPer::Trillion => -12,
+        }
+    }
+
+    /// Splits a string such as `±20ppm` into a (signed) string of decimal digits and a power of ten,
+    /// if it ends with one of these units.
+    ///
+    /// Symbols are case insensitive. A leading `±`, as in a symmetric tolerance, is read as the magnitude.
+    /// SI prefixes are not accepted.
+    pub(crate) fn parse(s: &str) -> Option<Result<ParsedDecimal, Error>> {
+        let s = s.trim();
+        let (unit, number) = Self::ALL
+            .iter()
+            .find_map(|u| strip_suffix_ignore_case(s, u.symbol()).map(|number| (u, number)))?;
+        let number = number.trim_end();
+        let number = number.strip_prefix('±').map_or(number, str::trim_start);
+        let parsed = parse_decimal(number, false);
+        if !parsed.is_plain_number() {
+            return Some(Err(Error::ParseError));
+        }
+        Some(Ok(ParsedDecimal {
+            pow10: parsed.pow10 + unit.pow10(),
+            ..parsed
+        }))
+    }
+}
+
+impl FromStr for PartsPer {
+    type Err = Error;
+
+    /// Parses a unit from its symbol, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|u| s.eq_ignore_ascii_case(u.symbol()))
+            .ok_or(Error::ParseError)
+    }
+}
+
+impl Display for PartsPer {
+    /// Outputs the symbol
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl<T: EQSupported<T>> EngineeringQuantity<T> {
+    /// Constructor from a number of parts, for example 20 ppm
+    ///
+    /// Construction fails if the number would overflow the storage type `T`.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, PartsPer};
+    /// let q = EQ::from_parts_per(EQ::<i64>::from(20), PartsPer::Million).unwrap();
+    /// assert_eq!(q.to_raw(), (20, -2));
+    /// ```
+    pub fn from_parts_per(parts: Self, unit: PartsPer) -> Result<Self, Error> {
+        parts.scale_pow10(unit.pow10())
+    }
+
+    /// Expresses the quantity as a number of parts, for example of ppm.
+    ///
+    /// This is exact, so may overflow the storage type `T`. Together with [`EngineeringQuantity::from_parts_per()`],
+    /// this converts between units:
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, PartsPer};
+    /// let q = EQ::from_parts_per(EQ::<i64>::from(1000), PartsPer::Million).unwrap();
+    /// assert_eq!(q.to_parts_per(PartsPer::Percent).unwrap().to_string(), "100m"); // 0.1%
+    /// assert_eq!(q.to_parts_per(PartsPer::Billion).unwrap().to_string(), "1M"); // 1,000,000 ppb
+    /// ```
+    pub fn to_parts_per(&self, unit: PartsPer) -> Result<Self, Error> {
+        self.clone().scale_pow10(-unit.pow10())
+    }
+}
+
+/// A wrapper type which displays a quantity as a dimensionless ratio, for example `0.1%`.
+/// It implements [`Display`].
+///
+/// This type may be conveniently created by [`DisplayAdapter::with_parts_per()`].
+#[derive(Copy, Clone, Debug)]
+pub struct PartsPerDisplayAdapter<T: EQSupported<T>> {
+    /// The value to be displayed
+    pub value: EngineeringQuantity<T>,
+    /// The unit to display the value in
+    pub unit: PartsPer,
+    /// The precision at which to display, or 0 to work it out losslessly
+    pub max_significant_figures: usize,
+    /// Always emit the precision requested, even any unnecessary untrailing zeroes after the decimal point.
+    pub strict: bool,
+    /// If set, the precision is a number of digits after the decimal point,
+    /// and `max_significant_figures` is ignored.
+    pub decimal_places: Option<usize>,
+}
+
+impl<T: EQSupported<T>> DisplayAdapter<T> {
+    /// Writes the quantity as a dimensionless ratio: a percentage, or parts per million, billion or trillion.
+    ///
+    /// The precision options carry over; the multiplier and unit options do not apply.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, PartsPer};
+    /// use std::str::FromStr as _;
+    /// let q = EQ::<i64>::from_str("50ppb").unwrap();
+    /// assert_eq!(q.with_precision(3).with_parts_per(PartsPer::Billion).to_string(), "50ppb");
+    /// assert_eq!(q.with_precision(3).with_parts_per(PartsPer::Million).to_string(), "0.05ppm");
+    /// ```
+    #[must_use]
+    pub fn with_parts_per(self, unit: PartsPer) -> PartsPerDisplayAdapter<T> {
+        PartsPerDisplayAdapter {
+            value: self.value,
+            unit,
+            max_significant_figures: self.max_significant_figures,
+            strict: self.strict,
+            decimal_places: self.decimal_places,
+        }
+    }
+}
+
+impl<T: EQSupported<T>> Display for PartsPerDisplayAdapter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (negative, digits) = sign_and_digits(&self.value.significand);
+        Style {
+            max_significant_figures: self.max_significant_figures,
+            strict: self.strict,
+            decimal_places: self.decimal_places,
+            prefixes: PrefixSet::empty().with(0),
+            ..Style::default()
+        }
+        .write(
+            f,
+            negative,
+            digits,
+            3 * i32::from(self.value.exponent) - self.unit.pow10(),
+        )?;
+        write!(f, "{}", self.unit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::PartsPer;
+    use crate::{EngineeringQuantity as EQ, Error};
+
+    #[test]
+    fn parse() {
+        for (s, raw) in [
+            ("0.1%", (1, -1)),
+            ("50%", (500, -1)),
+            ("20ppm", (20, -2)),
+            ("±20ppm", (20, -2)),
+            ("± 20 ppm", (20, -2)),
+            ("-20PPM", (-20, -2)),
+            ("50ppb", (50, -3)),
+            ("1.5ppt", (1500, -5)),
+        ] {
+            assert_eq!(EQ::<i64>::from_str(s).unwrap().to_raw(), raw, "{s}");
+        }
+        assert_eq!(
+            EQ::<i64>::from_str("0.10%").unwrap().significant_figures(),
+            Some(2)
+        );
+        // The default FromStr accepts ratios, and they land in the usual representation
+        assert_eq!(EQ::<i64>::from_str("20ppm"), EQ::from_str("20μ"));
+        assert_eq!(EQ::<i64>::from_str("0.1%"), EQ::from_str("1m"));
+        for s in ["%", "ppm", "20kppm", "20 p.p.m.", "20%%"] {
+            assert_eq!(EQ::<i64>::from_str(s), Err(Error::ParseError), "{s}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        let q = EQ::<i64>::from_str("1000ppm").unwrap();
+        for (unit, s) in [
+            (PartsPer::Percent, "0.1%"),
+            (PartsPer::Million, "1000ppm"),
+            (PartsPer::Billion, "1000000ppb"),
+            (PartsPer::Trillion, "1000000000ppt"),
+        ] {
+            assert_eq!(q.with_precision(0).with_parts_per(unit).to_string(), s);
+            assert_eq!(PartsPer::from_str(unit.symbol()), Ok(unit));
+        }
+        let q = EQ::<i64>::from_str("-12.345%").unwrap();
+        assert_eq!(
+            q.with_precision(3)
+                .with_parts_per(PartsPer::Percent)
+                .to_string(),
+            "-12.3%"
+        );
+        assert_eq!(
+            q.with_strict_decimal_places(4)
+                .with_parts_per(PartsPer::Percent)
+                .to_string(),
+            "-12.3450%"
+        );
+        assert_eq!(
+            q.with_precision(2)
+                .with_parts_per(PartsPer::Million)
+                .to_string(),
+            "-123450ppm"
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let q = EQ::from_parts_per(EQ::<i64>::from(1000), PartsPer::Million).unwrap();
+        assert_eq!(
+            q.to_parts_per(PartsPer::Percent).unwrap(),
+            EQ::from_str("0.1").unwrap()
+        );
+        assert_eq!(
+            q.to_parts_per(PartsPer::Trillion).unwrap(),
+            EQ::from(1_000_000_000)
+        );
+        let q = EQ::from_parts_per(EQ::<i32>::from(5), PartsPer::Percent).unwrap();
+        assert_eq!(q.to_raw(), (50, -1));
+        assert_eq!(
+            EQ::from_parts_per(EQ::<i32>::from(i32::MAX), PartsPer::Percent),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            EQ::<i32>::from(1).to_parts_per(PartsPer::Trillion),
+            Err(Error::Overflow)
+        );
+    }
+}
@@ -0,0 +1,524 @@
+//! SI prefixes and prefix sets
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{EQSupported, EngineeringQuantity, Error};
+
+/// An SI prefix.
+///
+/// The variants are in ascending order of size.
+/// ```
+/// use engineering_repr::Prefix;
+/// use std::str::FromStr as _;
+/// let k = Prefix::from_str("k").unwrap();
+/// assert_eq!(k, Prefix::Kilo);
+/// assert_eq!(k.name(), "kilo");
+/// assert_eq!(k.pow10(), 3);
+/// assert_eq!(k.exponent(), Some(1));
+/// assert_eq!(Prefix::Micro.to_string(), "μ");
+/// assert_eq!(Prefix::Micro.ascii_symbol(), "u");
+/// assert_eq!(Prefix::iter().filter(|p| p.is_engineering()).count(), 20);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum Prefix {
+    Quecto,
+    Ronto,
+    Yocto,
+    Zepto,
+    Atto,
+    Femto,
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    Deca,
+    Hecto,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+    Exa,
+    Zetta,
+    Yotta,
+    Ronna,
+    Quetta,
+}
+
+/// Prefix metadata
+struct Entry {
+    prefix: Prefix,
+    symbol: &'static str,
+    ascii_symbol: &'static str,
+    name: &'static str,
+    pow10: i8,
+}
+
+macro_rules! entries {
+    {$($prefix:ident $symbol:literal $ascii:literal $name:literal $pow10:literal),+ $(,)?} => {
+        /// All of the prefixes, in the same order as the enum
+        static PREFIXES: [Entry; 24] = [$(
+            Entry { prefix: Prefix::$prefix, symbol: $symbol, ascii_symbol: $ascii, name: $name, pow10: $pow10 },
+        )+];
+    }
+}
+
+entries! {
+    Quecto "q" "q" "quecto" -30,
+    Ronto "r" "r" "ronto" -27,
+    Yocto "y" "y" "yocto" -24,
+    Zepto "z" "z" "zepto" -21,
+    Atto "a" "a" "atto" -18,
+    Femto "f" "f" "femto" -15,
+    Pico "p" "p" "pico" -12,
+    Nano "n" "n" "nano" -9,
+    Micro "μ" "u" "micro" -6,
+    Milli "m" "m" "milli" -3,
+    Centi "c" "c" "centi" -2,
+    Deci "d" "d" "deci" -1,
+    Deca "da" "da" "deca" 1,
+    Hecto "h" "h" "hecto" 2,
+    Kilo "k" "k" "kilo" 3,
+    Mega "M" "M" "mega" 6,
+    Giga "G" "G" "giga" 9,
+    Tera "T" "T" "tera" 12,
+    Peta "P" "P" "peta" 15,
+    Exa "E" "E" "exa" 18,
+    Zetta "Z" "Z" "zetta" 21,
+    Yotta "Y" "Y" "yotta" 24,
+    Ronna "R" "R" "ronna" 27,
+    Quetta "Q" "Q" "quetta" 30,
+}
+
+impl Prefix {
+    fn entry(self) -> &'static Entry {
+        &PREFIXES[self as usize]
+    }
+    /// Iterates over all of the prefixes, from quecto to quetta
+    pub fn iter() -> impl Iterator<Item = Prefix> {
+        PREFIXES.iter().map(|e| e.prefix)
+    }
+    /// The standard symbol, e.g. `k` or `μ`
+    #[must_use]
+    pub fn symbol(self) -> &'static str {
+        self.entry().symbol
+    }
+    /// The symbol in plain ASCII. This is the same as [`symbol`](Self::symbol), except for micro (`u`).
+    #[must_use]
+    pub fn ascii_symbol(self) -> &'static str {
+        self.entry().ascii_symbol
+    }
+    /// The full name, in lower case, e.g. `kilo`
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        self.entry().name
+    }
+    /// The power of ten this prefix represents, e.g. 3 for kilo
+    #[must_use]
+    pub fn pow10(self) -> i32 {
+        self.entry().pow10.into()
+    }
+    /// The exponent in powers of 1000 (as used by [`EngineeringQuantity::to_raw()`]), e.g. 1 for kilo.
+    ///
+    /// This is `None` for the non-engineering prefixes centi, deci, deca and hecto.
+    #[must_use]
+    pub fn exponent(self) -> Option<i8> {
+        let pow10 = self.entry().pow10;
+        (pow10 % 3 == 0).then_some(pow10 / 3)
+    }
+    /// Is this an engineering prefix (a power of 1000)?
+    #[must_use]
+    pub fn is_engineering(self) -> bool {
+        self.exponent().is_some()
+    }
+    /// The prefix for the given power of ten, if there is one
+    #[must_use]
+    pub fn from_pow10(pow10: i32) -> Option<Self> {
+        PREFIXES
+            .iter()
+            .find(|e| i32::from(e.pow10) == pow10)
+            .map(|e| e.prefix)
+    }
+    /// The prefix for the given exponent in powers of 1000, if there is one
+    #[must_use]
+    pub fn from_exponent(exponent: i8) -> Option<Self> {
+        Self::from_pow10(3 * i32::from(exponent))
+    }
+    /// The prefix with the given single-character symbol (or ASCII symbol), if there is one.
+    ///
+    /// Note that `d` is deci; deca (`da`) has no single-character symbol.
+    /// The micro sign (U+00B5), which is often typed in place of the Greek letter mu, is read as micro.
+    pub(crate) fn from_char(c: char) -> Option<Self> {
+        if c == '\u{b5}' {
+            return Some(Prefix::Micro);
+        }
+        let is = |sym: &str| {
+            let mut chars = sym.chars();
+            chars.next() == Some(c) && chars.next().is_none()
+        };
+        PREFIXES
+            .iter()
+            .find(|e| is(e.symbol) || is(e.ascii_symbol))
+            .map(|e| e.prefix)
+    }
+}
+
+impl FromStr for Prefix {
+    type Err = Error;
+
+    /// Parses a prefix from its symbol (`k`, `μ` or `u`) or its full name (`kilo`).
+    ///
+    /// Symbols are case sensitive; names are not.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PREFIXES
+            .iter()
+            .find(|e| s == e.symbol || s == e.ascii_symbol || s.eq_ignore_ascii_case(e.name))
+            .map(|e| e.prefix)
+            .ok_or(Error::ParseError)
+    }
+}
+
+impl Display for Prefix {
+    /// Outputs the standard symbol
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl<T: EQSupported<T>> EngineeringQuantity<T> {
+    /// Constructor from a significand and an SI prefix
+    ///
+    /// Construction fails if the number would overflow the storage type `T`.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, Prefix};
+    /// let q = EQ::from_prefix(47u32, Prefix::Kilo).unwrap();
+    /// assert_eq!(q.to_raw(), (47, 1));
+    /// let q = EQ::from_prefix(3u32, Prefix::Hecto).unwrap();
+    /// assert_eq!(q.to_raw(), (300, 0));
+    /// ```
+    pub fn from_prefix(significand: T, prefix: Prefix) -> Result<Self, Error> {
+        Self::from_raw_unchecked(significand, 0).scale_pow10(prefix.pow10())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// PREFIX SETS
+
+/// A set of SI prefixes, used to restrict the prefixes accepted by a [`Parser`](crate::Parser)
+/// or output by a [`DisplayAdapter`](crate::DisplayAdapter).
+///
+/// Prefixes are identified by their exponent, in powers of 1000 (like [`EngineeringQuantity::to_raw()`](crate::EngineeringQuantity::to_raw)),
+/// so `k` is 1, `M` is 2, `m` is -1 and so on.
+/// Exponent 0 represents the absence of a prefix.
+///
+/// The non-engineering prefixes centi, deci, deca and hecto may also be included;
+/// see [`PrefixSet::non_engineering()`].
+/// ```
+/// use engineering_repr::PrefixSet;
+/// // Everything except exa and peta
+/// let set = PrefixSet::all().without(6).without(5);
+/// assert!(set.contains(4));
+/// assert!(!set.contains(5));
+/// // Only the prefixes listed
+/// let set = PrefixSet::from_symbols("μnp").unwrap();
+/// assert!(set.contains(-2));
+/// assert!(!set.contains(-1));
+/// assert!(!set.contains(0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PrefixSet {
+    /// Bit `n + 30` is set if the prefix for 10^n is in the set
+    bits: u64,
+}
+
+/// Largest supported exponent (in powers of 1000)
+const MAX_EXPONENT: i8 = 10;
+
+/// Bit for the prefix with the given exponent (in powers of 1000), or 0 if out of range
+fn bit(exponent: i8) -> u64 {
+    bit_pow10(3 * i32::from(exponent))
+}
+
+/// Bit for the prefix with the given power of ten, or 0 if out of range
+fn bit_pow10(pow10: i32) -> u64 {
+    if pow10.unsigned_abs() > 3 * u32::from(MAX_EXPONENT.unsigned_abs()) {
+        return 0;
+    }
+    1 << (pow10 + 30)
+}
+
+/// Bits for the non-engineering prefixes: centi, deci, deca, hecto
+const NON_ENGINEERING: u64 = (0b11 << 28) | (0b11 << 31);
+
+impl PrefixSet {
+    /// The set of all supported prefixes, from q (10^-30) to Q (10^30), and the absence of a prefix
+    #[must_use]
+    pub fn all() -> Self {
+        (-MAX_EXPONENT..=MAX_EXPONENT).fold(Self::empty(), Self::with)
+    }
+    /// The empty set
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { bits: 0 }
+    }
+    /// The set of prefixes with exponents from `min` to `max` inclusive
+    /// ```
+    /// use engineering_repr::PrefixSet;
+    /// let set = PrefixSet::range(-1, 2);
+    /// assert_eq!(set, PrefixSet::from_symbols("mkM").unwrap().with(0));
+    /// ```
+    #[must_use]
+    pub fn range(min: i8, max: i8) -> Self {
+        (min..=max).fold(Self::empty(), Self::with)
+    }
+    /// The non-engineering prefixes: c (10^-2), d (10^-1), da (10^1) and h (10^2).
+    ///
+    /// These are not included in [`PrefixSet::all()`].
+    /// ```
+    /// use engineering_repr::PrefixSet;
+    /// let set = PrefixSet::all().union(PrefixSet::non_engineering());
+    /// assert_eq!(set.without(0), PrefixSet::from_symbols("qryzafpnμmcddahkMGTPEZYRQ").unwrap());
+    /// ```
+    #[must_use]
+    pub fn non_engineering() -> Self {
+        Self {
+            bits: NON_ENGINEERING,
+        }
+    }
+    /// Parses a list of prefix symbols, for example `"kMG"`.
+    ///
+    /// Micro may be written as `μ` or `u`. The non-engineering prefixes `c`, `d`, `da` and `h` are also accepted;
+    /// note that `da` is always read as deca, not deci followed by atto.
+    /// The absence of a prefix is not included; add it with `with(0)` if required.
+    pub fn from_symbols(symbols: &str) -> Result<Self, Error> {
+        let mut set = Self::empty();
+        let mut chars = symbols.chars().peekable();
+        while let Some(c) = chars.next() {
+            let prefix = match Prefix::from_char(c).ok_or(Error::ParseError)? {
+                Prefix::Deci if chars.next_if_eq(&'a').is_some() => Prefix::Deca,
+                p => p,
+            };
+            set.bits |= bit_pow10(prefix.pow10());
+        }
+        Ok(set)
+    }
+    /// Adds the prefix with the given exponent (in powers of 1000) to the set.
+    ///
+    /// Exponents outside of the supported range are ignored.
+    #[must_use]
+    pub fn with(self, exponent: i8) -> Self {
+        Self {
+            bits: self.bits | bit(exponent),
+        }
+    }
+    /// Removes the prefix with the given exponent (in powers of 1000) from the set.
+    #[must_use]
+    pub fn without(self, exponent: i8) -> Self {
+        Self {
+            bits: self.bits & !bit(exponent),
+        }
+    }
+    /// Does the set contain the prefix with the given exponent (in powers of 1000)?
+    #[must_use]
+    pub fn contains(self, exponent: i8) -> bool {
+        let b = bit(exponent);
+        b != 0 && self.bits & b == b
+    }
+    /// Intersection of two sets
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+    /// Union of two sets
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Adds the prefix with the given power of ten to the set.
+    ///
+    /// Powers outside of the supported range are ignored.
+    pub(crate) fn with_pow10(self, pow10: i32) -> Self {
+        Self {
+            bits: self.bits | bit_pow10(pow10),
+        }
+    }
+    /// Does the set contain the prefix with the given power of ten?
+    pub(crate) fn contains_pow10(self, pow10: i32) -> bool {
+        let b = bit_pow10(pow10);
+        b != 0 && self.bits & b == b
+    }
+    /// Does the set contain any of the non-engineering prefixes?
+    pub(crate) fn has_non_engineering(self) -> bool {
+        self.bits & NON_ENGINEERING != 0
+    }
+    /// The subset of prefixes with exponents (in powers of 1000) from `min` to `max` inclusive.
+    ///
+    /// Non-engineering prefixes within the range are retained.
+    pub(crate) fn within(self, min: i8, max: i8) -> Self {
+        let mask = (3 * i32::from(min)..=3 * i32::from(max)).fold(0, |m, p| m | bit_pow10(p));
+        Self {
+            bits: self.bits & mask,
+        }
+    }
+
+    /// Chooses the best prefix in this set for a number whose most significant digit is at 10^`msd`,
+    /// returning its power of ten.
+    ///
+    /// This is the largest prefix which does not exceed the number; failing that, the smallest prefix.
+    /// Returns None if the set is empty.
+    pub(crate) fn best_pow10(self, msd: i32) -> Option<i32> {
+        let max_pow10 = 3 * i32::from(MAX_EXPONENT);
+        let candidates = (-max_pow10..=max_pow10).filter(|p| self.contains_pow10(*p));
+        let mut best = None;
+        for p in candidates {
+            if p <= msd || best.is_none() {
+                best = Some(p);
+            }
+            if p >= msd {
+                break;
+            }
+        }
+        best
+    }
+}
+
+impl Default for PrefixSet {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::{Prefix, PrefixSet};
+    use crate::{EngineeringQuantity as EQ, Error};
+
+    #[test]
+    fn prefix_metadata() {
+        let all: Vec<_> = Prefix::iter().collect();
+        assert_eq!(all.len(), 24);
+        assert!(all
+            .windows(2)
+            .all(|w| w[0] < w[1] && w[0].pow10() < w[1].pow10()));
+        for p in Prefix::iter() {
+            assert_eq!(Prefix::from_pow10(p.pow10()), Some(p));
+            assert_eq!(Prefix::from_str(p.symbol()), Ok(p));
+            assert_eq!(Prefix::from_str(p.ascii_symbol()), Ok(p));
+            assert_eq!(Prefix::from_str(p.name()), Ok(p));
+            assert_eq!(Prefix::from_str(&p.name().to_uppercase()), Ok(p));
+            if let Some(e) = p.exponent() {
+                assert_eq!(Prefix::from_exponent(e), Some(p));
+            }
+        }
+        assert_eq!(Prefix::Deca.exponent(), None);
+        assert_eq!(Prefix::Quetta.exponent(), Some(10));
+        assert_eq!(Prefix::from_exponent(0), None);
+        assert_eq!(Prefix::from_str("K"), Err(Error::ParseError));
+        assert_eq!(Prefix::from_str(""), Err(Error::ParseError));
+    }
+
+    #[test]
+    fn from_prefix() {
+        for (sig, prefix, raw) in [
+            (47, Prefix::Kilo, (47, 1)),
+            (-1, Prefix::Milli, (-1, -1)),
+            (15, Prefix::Centi, (150, -1)),
+            (5, Prefix::Deci, (500, -1)),
+            (2, Prefix::Deca, (20, 0)),
+        ] {
+            assert_eq!(EQ::<i64>::from_prefix(sig, prefix).unwrap().to_raw(), raw);
+        }
+        assert_eq!(
+            EQ::<u8>::from_prefix(26, Prefix::Hecto).unwrap_err(),
+            Error::Overflow
+        );
+        assert_eq!(
+            EQ::<u32>::from_prefix(5, Prefix::Tera).unwrap_err(),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn membership() {
+        let set = PrefixSet::all();
+        for e in -10..=10 {
+            assert!(set.contains(e));
+        }
+        assert!(!set.contains(11));
+        assert!(!set.contains(-11));
+        let set = PrefixSet::empty();
+        for e in -10..=10 {
+            assert!(!set.contains(e));
+        }
+        assert!(!PrefixSet::all().without(0).contains(0));
+        // Out of range exponents are ignored
+        assert_eq!(PrefixSet::empty().with(42), PrefixSet::empty());
+    }
+
+    #[test]
+    fn symbols() {
+        let set = PrefixSet::from_symbols("kuG").unwrap();
+        assert_eq!(set, PrefixSet::empty().with(1).with(-2).with(3));
+        assert_eq!(PrefixSet::from_symbols("kx"), Err(Error::ParseError));
+        assert_eq!(PrefixSet::from_symbols(""), Ok(PrefixSet::empty()));
+    }
+
+    #[test]
+    fn non_engineering() {
+        let set = PrefixSet::from_symbols("cddah").unwrap();
+        assert_eq!(set, PrefixSet::non_engineering());
+        assert!(set.has_non_engineering());
+        assert!(!PrefixSet::all().has_non_engineering());
+        assert_eq!(
+            PrefixSet::from_symbols("dak").unwrap(),
+            PrefixSet::from_symbols("kda").unwrap()
+        );
+        let set = PrefixSet::all().union(set);
+        assert_eq!(set.within(0, 0), PrefixSet::empty().with(0));
+        assert_eq!(
+            set.within(-1, 0),
+            PrefixSet::from_symbols("mcd").unwrap().with(0)
+        );
+        assert_eq!(set.best_pow10(1), Some(1));
+        assert_eq!(set.best_pow10(2), Some(2));
+        assert_eq!(set.best_pow10(4), Some(3));
+    }
+
+    #[test]
+    fn best_pow10() {
+        let set = PrefixSet::all().without(-1).without(6);
+        for (msd, expected) in &[
+            (0, 0),
+            (2, 0),
+            (3, 3),
+            (-1, -6), // no milli
+            (-3, -6), // no milli
+            (-4, -6),
+            (18, 15), // no exa
+            (30, 30),
+            (40, 30),
+            (-40, -30),
+        ] {
+            assert_eq!(set.best_pow10(*msd), Some(*expected), "msd {msd}");
+        }
+        let set = PrefixSet::from_symbols("kM").unwrap();
+        assert_eq!(set.best_pow10(-3), Some(3));
+        assert_eq!(set.best_pow10(12), Some(6));
+        let set = PrefixSet::range(2, 3).intersection(set);
+        assert_eq!(set.best_pow10(-3), Some(6));
+        let set = PrefixSet::range(3, 4).intersection(set);
+        assert_eq!(set.best_pow10(0), None);
+    }
+}
@@ -0,0 +1,384 @@
+//! Helper modules for use with `#[serde(with = ...)]`
+//!
+//! By default, an [`EngineeringQuantity`] is serialized as a string at automatic precision.
+//! These modules select a different representation for an individual field:
+//!
+//! | Module             | Serialized form                           | Example    |
+//! |--------------------|-------------------------------------------|------------|
+//! | [`as_integer`]     | An integer (fails if there is a fraction) | `1500`     |
+//! | [`as_f64`]         | A floating-point number                   | `0.0015`   |
+//! | [`as_rkm`]         | An RKM string at automatic precision      | `"1k5"`    |
+//! | [`as_tuple`]       | A `[significand, exponent]` pair          | `[15, -1]` |
+//! | [`with_precision`] | A string at a given precision             | `"1.50k"`  |
+//!
+//! Each module has a matching deserializer, which accepts the same representation.
+//!
+//! [`round_floats`] serializes as usual, but its deserializer rounds floating-point input
+//! rather than requiring it to convert exactly.
+//!
+//! ```
+//! use engineering_repr::EngineeringQuantity as EQ;
+//! use engineering_repr::serde_helpers;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Resistor {
+//!     #[serde(with = "serde_helpers::as_integer")]
+//!     ohms: EQ<u32>,
+//!     #[serde(with = "serde_helpers::as_rkm")]
+//!     label: EQ<u32>,
+//! }
+//!
+//! let r = Resistor { ohms: EQ::from(4700u32), label: EQ::from(4700u32) };
+//! let json = serde_json::to_string(&r).unwrap();
+//! assert_eq!(json, r#"{"ohms":4700,"label":"4k7"}"#);
+//! ```
+//!
+//! <div class="warning">
+//! Available on feature <b>serde</b> only.
+//! </div>
+
+use std::str::FromStr;
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::serde_support::describe_error;
+use crate::{EQSupported, EngineeringQuantity};
+
+/// Parses a string, mapping errors into the deserializer's error type
+fn parse<'de, D, T>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: EQSupported<T> + FromStr,
+{
+    let s = String::deserialize(deserializer)?;
+    EngineeringQuantity::from_str(&s).map_err(|e| {
+        let negative = s.trim_start().starts_with('-');
+        de::Error::custom(describe_error::<T>(format_args!("'{s}'"), negative, e))
+    })
+}
+
+/// Serializes as an integer.
+///
+/// Serialization fails if the quantity has a fractional part, or does not fit into the storage type.
+/// ```
+/// use engineering_repr::EngineeringQuantity as EQ;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Freq {
+///     #[serde(with = "engineering_repr::serde_helpers::as_integer")]
+///     hz: EQ<u64>,
+/// }
+/// let f = Freq { hz: EQ::from_raw(2400, 2).unwrap() };
+/// assert_eq!(serde_json::to_string(&f).unwrap(), r#"{"hz":2400000000}"#);
+/// let f = Freq { hz: EQ::from_raw(15, -1).unwrap() };
+/// assert!(serde_json::to_string(&f).is_err());
+/// ```
+pub mod as_integer {
+    use super::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use crate::{EQSupported, EngineeringQuantity};
+
+    /// Serializes the quantity as an integer
+    pub fn serialize<T, S>(value: &EngineeringQuantity<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T> + Serialize,
+        S: Serializer,
+    {
+        value
+            .try_to_integer_exact()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserializes an integer into a quantity
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        EngineeringQuantity::from_raw(value, 0).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes as a 64-bit float.
+///
+/// Serialization fails if the quantity cannot be represented as a float.
+/// Deserialization is exact, as for the `TryFrom<f64>` conversion;
+/// see [`round_floats`] to round instead.
+/// ```
+/// use engineering_repr::EngineeringQuantity as EQ;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Cap {
+///     #[serde(with = "engineering_repr::serde_helpers::as_f64")]
+///     farads: EQ<i64>,
+/// }
+/// let c = Cap { farads: EQ::from_raw(47, -2).unwrap() };
+/// let json = serde_json::to_string(&c).unwrap();
+/// assert_eq!(json, r#"{"farads":0.000047}"#);
+/// let c2: Cap = serde_json::from_str(&json).unwrap();
+/// assert_eq!(c2.farads, c.farads);
+/// ```
+pub mod as_f64 {
+    use super::{de, describe_error, ser, Deserialize, Deserializer, Serializer};
+    use crate::{EQSupported, EngineeringQuantity, Error};
+    use std::str::FromStr;
+
+    /// Serializes the quantity as a float
+    pub fn serialize<T, S>(value: &EngineeringQuantity<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T>,
+        f64: TryFrom<EngineeringQuantity<T>, Error = Error>,
+        S: Serializer,
+    {
+        let f = f64::try_from(value.clone()).map_err(ser::Error::custom)?;
+        serializer.serialize_f64(f)
+    }
+
+    /// Deserializes a float into a quantity
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + FromStr,
+        D: Deserializer<'de>,
+    {
+        let f = f64::deserialize(deserializer)?;
+        EngineeringQuantity::try_from(f)
+            .map_err(|e| de::Error::custom(describe_error::<T>(f, f < 0.0, e)))
+    }
+}
+
+/// Serializes as an RKM string (`4k7`) at automatic precision.
+///
+/// Deserialization accepts standard or RKM notation.
+pub mod as_rkm {
+    use super::{parse, Deserializer, Serializer};
+    use crate::{EQSupported, EngineeringQuantity};
+    use std::str::FromStr;
+
+    /// Serializes the quantity as an RKM string
+    pub fn serialize<T, S>(value: &EngineeringQuantity<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T>,
+        S: Serializer,
+    {
+        serializer.collect_str(&value.rkm_with_precision(0))
+    }
+
+    /// Deserializes a string into a quantity
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + FromStr,
+        D: Deserializer<'de>,
+    {
+        parse(deserializer)
+    }
+}
+
+/// Serializes as a `[significand, exponent]` pair, as returned by [`EngineeringQuantity::to_raw()`].
+/// ```
+/// use engineering_repr::EngineeringQuantity as EQ;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Ind {
+///     #[serde(with = "engineering_repr::serde_helpers::as_tuple")]
+///     henries: EQ<i32>,
+/// }
+/// let i = Ind { henries: EQ::from_raw(22, -2).unwrap() };
+/// let json = serde_json::to_string(&i).unwrap();
+/// assert_eq!(json, r#"{"henries":[22,-2]}"#);
+/// let i2: Ind = serde_json::from_str(&json).unwrap();
+/// assert_eq!(i2.henries, i.henries);
+/// ```
+pub mod as_tuple {
+    use super::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use crate::{EQSupported, EngineeringQuantity};
+
+    /// Serializes the quantity as a pair
+    pub fn serialize<T, S>(value: &EngineeringQuantity<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T> + Serialize,
+        S: Serializer,
+    {
+        value.clone().to_raw().serialize(serializer)
+    }
+
+    /// Deserializes a pair into a quantity
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let (significand, exponent) = <(T, i8)>::deserialize(deserializer)?;
+        EngineeringQuantity::from_raw(significand, exponent).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes as a string with a given number of significant figures.
+///
+/// As this module is parameterised, use `serialize_with` and `deserialize_with`
+/// rather than `with`:
+/// ```
+/// use engineering_repr::EngineeringQuantity as EQ;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Volts {
+///     #[serde(
+///         serialize_with = "engineering_repr::serde_helpers::with_precision::serialize::<3, _, _>",
+///         deserialize_with = "engineering_repr::serde_helpers::with_precision::deserialize"
+///     )]
+///     value: EQ<i32>,
+/// }
+/// let v = Volts { value: EQ::from(12_345) };
+/// assert_eq!(serde_json::to_string(&v).unwrap(), r#"{"value":"12.3k"}"#);
+/// ```
+pub mod with_precision {
+    use super::{parse, Deserializer, Serializer};
+    use crate::{EQSupported, EngineeringQuantity};
+    use std::str::FromStr;
+
+    /// Serializes the quantity as a string with `N` significant figures
+    pub fn serialize<const N: usize, T, S>(
+        value: &EngineeringQuantity<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T>,
+        S: Serializer,
+    {
+        serializer.collect_str(&value.with_precision(N))
+    }
+
+    /// Deserializes a string into a quantity
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + FromStr,
+        D: Deserializer<'de>,
+    {
+        parse(deserializer)
+    }
+}
+
+/// Deserializes as usual (from a string, integer or float), but rounds floats
+/// to a given number of significant figures instead of requiring them to convert exactly.
+///
+/// Serialization is as usual, i.e. as a string at automatic precision.
+/// ```
+/// use engineering_repr::EngineeringQuantity as EQ;
+/// #[derive(serde::Deserialize)]
+/// struct Gain {
+///     #[serde(deserialize_with = "engineering_repr::serde_helpers::round_floats::deserialize::<3, _, _>")]
+///     value: EQ<i32>,
+/// }
+/// let g: Gain = serde_json::from_str(r#"{"value":0.30000000000000004}"#).unwrap();
+/// assert_eq!(g.value.to_string(), "300m");
+/// ```
+pub mod round_floats {
+    use super::{Deserializer, Serializer};
+    use crate::serde_support::EQVisitor;
+    use crate::{EQSupported, EngineeringQuantity};
+    use serde::Serialize as _;
+    use std::str::FromStr;
+
+    /// Serializes the quantity as a string at automatic precision
+    pub fn serialize<T, S>(value: &EngineeringQuantity<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EQSupported<T>,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a quantity, rounding floats to `N` significant figures
+    pub fn deserialize<'de, const N: usize, T, D>(
+        deserializer: D,
+    ) -> Result<EngineeringQuantity<T>, D::Error>
+    where
+        T: EQSupported<T> + FromStr + TryFrom<u128> + TryFrom<i128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(EQVisitor::rounding(N))
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::EngineeringQuantity as EQ;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Everything {
+        #[serde(with = "super::as_integer")]
+        integer: EQ<i64>,
+        #[serde(with = "super::as_f64")]
+        float: EQ<i64>,
+        #[serde(with = "super::as_rkm")]
+        rkm: EQ<i64>,
+        #[serde(with = "super::as_tuple")]
+        tuple: EQ<i64>,
+        #[serde(
+            serialize_with = "super::with_precision::serialize::<2, _, _>",
+            deserialize_with = "super::with_precision::deserialize"
+        )]
+        precision: EQ<i64>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let e = Everything {
+            integer: EQ::from_raw(47, 1).unwrap(),
+            float: EQ::from_raw(-15, -1).unwrap(),
+            rkm: EQ::from_raw(4700, 0).unwrap(),
+            tuple: EQ::from_raw(1, 3).unwrap(),
+            precision: EQ::from_raw(1200, 0).unwrap(),
+        };
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(
+            json,
+            r#"{"integer":47000,"float":-0.015,"rkm":"4k7","tuple":[1,3],"precision":"1.2k"}"#
+        );
+        let e2: Everything = serde_json::from_str(&json).unwrap();
+        assert_eq!(e, e2);
+    }
+
+    #[test]
+    fn integer() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Int(#[serde(with = "super::as_integer")] EQ<i32>);
+        for (raw, expected) in &[((0, -3), "0"), ((3000, -1), "3"), ((-2, 2), "-2000000")] {
+            let v = Int(EQ::from_raw(raw.0, raw.1).unwrap());
+            assert_eq!(serde_json::to_string(&v).unwrap(), *expected);
+        }
+        for raw in &[(1, -1), (1, 4), (1, -5)] {
+            let v = Int(EQ::from_raw_unchecked(raw.0, raw.1));
+            let _ = serde_json::to_string(&v).unwrap_err();
+        }
+        let _ = serde_json::from_str::<Int>("1.5").unwrap_err();
+        let _ = serde_json::from_str::<Int>("\"1k\"").unwrap_err();
+        assert_eq!(
+            serde_json::from_str::<Int>("-42").unwrap().0.to_raw(),
+            (-42, 0)
+        );
+    }
+
+    #[test]
+    fn float() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Float(#[serde(with = "super::as_f64")] EQ<u32>);
+        let v: Float = serde_json::from_str("0.125").unwrap();
+        assert_eq!(v.0.to_raw(), (125, -1));
+        let v: Float = serde_json::from_str("4e6").unwrap();
+        assert_eq!(v.0.normalise().to_raw(), (4, 2));
+        let _ = serde_json::from_str::<Float>("-1").unwrap_err();
+        let _ = serde_json::from_str::<Float>("1e10").unwrap_err();
+    }
+
+    #[test]
+    fn tuple() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Tuple(#[serde(with = "super::as_tuple")] EQ<u16>);
+        let _ = serde_json::from_str::<Tuple>("[65, 2]").unwrap_err();
+        let _ = serde_json::from_str::<Tuple>("[65]").unwrap_err();
+        let v: Tuple = serde_json::from_str("[65, 1]").unwrap();
+        assert_eq!(v.0.to_raw(), (65, 1));
+    }
+}
@@ -1,10 +1,10 @@
 //! Serde support
 
-use std::{marker::PhantomData, str::FromStr};
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
 
 use serde::{de, Deserialize, Serialize};
 
-use crate::{EQSupported, EngineeringQuantity};
+use crate::{base_pow, EQSupported, EngineeringQuantity, Error};
 
 /// <div class="warning">
 /// Available on feature <b>serde</b> only.
@@ -19,10 +19,24 @@ impl<T: EQSupported<T>> Serialize for EngineeringQuantity<T> {
     }
 }
 
-struct EQVisitor<U: EQSupported<U>>(PhantomData<U>);
+/// Deserialization visitor, accepting strings, integers and floats
+pub(crate) struct EQVisitor<U: EQSupported<U>> {
+    /// If set, floats are rounded to this many significant figures; otherwise they must convert exactly
+    round_to: Option<usize>,
+    phantom: PhantomData<U>,
+}
 impl<U: EQSupported<U>> EQVisitor<U> {
-    fn new() -> Self {
-        Self(PhantomData)
+    pub(crate) fn new() -> Self {
+        Self {
+            round_to: None,
+            phantom: PhantomData,
+        }
+    }
+    pub(crate) fn rounding(significant_figures: usize) -> Self {
+        Self {
+            round_to: Some(significant_figures),
+            phantom: PhantomData,
+        }
     }
 }
 impl<U: EQSupported<U> + FromStr + std::convert::TryFrom<u128> + std::convert::TryFrom<i128>>
@@ -34,22 +48,30 @@ impl<U: EQSupported<U> + FromStr + std::convert::TryFrom<u128> + std::convert::T
     where
         E: de::Error,
     {
-        let res = EngineeringQuantity::from_str(v);
-        res.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+        EngineeringQuantity::from_str(v).map_err(|e| {
+            let negative = v.trim_start().starts_with('-');
+            match e {
+                Error::ParseError => describe_parse_error::<U, E>(v, &self),
+                e => de::Error::custom(describe_error::<U>(format_args!("'{v}'"), negative, e)),
+            }
+        })
     }
 
     fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
     where
         E: de::Error,
     {
-        let u = U::try_from(value).map_err(|_| de::Error::custom("failed to convert integer"))?;
+        let u = U::try_from(value)
+            .map_err(|_| de::Error::custom(describe_error::<U>(value, false, Error::Overflow)))?;
         Ok(Self::Value::from(u))
     }
     fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
     where
         E: de::Error,
     {
-        let u = U::try_from(value).map_err(|_| de::Error::custom("failed to convert integer"))?;
+        let u = U::try_from(value).map_err(|_| {
+            de::Error::custom(describe_error::<U>(value, value < 0, Error::Overflow))
+        })?;
         Ok(Self::Value::from(u))
     }
     fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
@@ -65,11 +87,91 @@ impl<U: EQSupported<U> + FromStr + std::convert::TryFrom<u128> + std::convert::T
         self.visit_i128(value.into())
     }
 
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let res = match self.round_to {
+            None => EngineeringQuantity::try_from(value),
+            Some(n) => EngineeringQuantity::from_f64_rounded(value, n),
+        };
+        res.map_err(|e| de::Error::custom(describe_error::<U>(value, value < 0.0, e)))
+    }
+
     fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        formatter.write_str("an engineering quantity (1M, 4.5k, etc) or an integer")
+        formatter.write_str("an engineering quantity (1M, 4.5k, etc) or a number")
+    }
+}
+
+/// Describes the range of a storage type, e.g. `max 4.29G`
+fn describe_range<U: EQSupported<U>>() -> String {
+    let describe = |v: U| {
+        EngineeringQuantity::<U>::from_raw_unchecked(v, 0)
+            .with_precision(3)
+            .to_string()
+    };
+    match (U::minimum(), U::maximum()) {
+        (_, None) => "unbounded".into(),
+        (Some(min), Some(max)) if min == U::ZERO => format!("max {}", describe(max)),
+        // The minimum itself has no positive counterpart, so cannot be displayed; it's close enough
+        (Some(min), Some(max)) => format!("min {}, max {}", describe(min + U::ONE), describe(max)),
+        (None, Some(max)) => format!("max {}", describe(max)),
+    }
+}
+
+/// Describes the smallest non-zero magnitude a storage type can hold, e.g. `1a`
+fn describe_resolution<U: EQSupported<U>>() -> String {
+    let mut exponent = 0i8;
+    while exponent > -10 && base_pow::<U>(usize::from(exponent.unsigned_abs()) + 1).is_some() {
+        exponent -= 1;
+    }
+    EngineeringQuantity::<U>::from_raw_unchecked(U::ONE, exponent).to_string()
+}
+
+/// Explains why a value could not be stored, in terms of the storage type
+pub(crate) fn describe_error<U: EQSupported<U>>(
+    value: impl Display,
+    negative: bool,
+    e: Error,
+) -> String {
+    let ty = std::any::type_name::<U>();
+    match e {
+        Error::Overflow | Error::Underflow if negative && U::is_unsigned() => {
+            format!("value {value} is negative, but {ty} is unsigned")
+        }
+        Error::Overflow => format!("value {value} overflows {ty} ({})", describe_range::<U>()),
+        Error::Underflow => format!(
+            "value {value} underflows {ty} (smallest {})",
+            describe_resolution::<U>()
+        ),
+        Error::ImpreciseConversion => {
+            format!("value {value} cannot be represented exactly in {ty}; it would need rounding")
+        }
+        e => format!("value {value}: {e}"),
     }
 }
 
+/// Explains a string parse failure.
+///
+/// A syntactically valid number may still fail to parse if its digits do not fit into the storage type.
+fn describe_parse_error<U: EQSupported<U>, E: de::Error>(v: &str, exp: &dyn de::Expected) -> E {
+    let Ok(wide) = EngineeringQuantity::<i128>::from_str(v) else {
+        return de::Error::invalid_value(de::Unexpected::Str(v), exp);
+    };
+    let integer = i128::from(wide);
+    let max = U::maximum().and_then(|m| m.to_i128()).unwrap_or(i128::MAX);
+    let min = U::minimum().and_then(|m| m.to_i128()).unwrap_or(i128::MIN);
+    let value = format_args!("'{v}'");
+    de::Error::custom(if integer < min || integer > max {
+        describe_error::<U>(value, integer < 0, Error::Overflow)
+    } else {
+        format!(
+            "value {value} has too many significant figures for {}",
+            std::any::type_name::<U>()
+        )
+    })
+}
+
 /// <div class="warning">
 /// Available on feature <b>serde</b> only.
 /// </div>
@@ -99,6 +201,91 @@ mod test {
         assert_eq!(e1, e2);
     }
 
+    #[test]
+    fn round_trip_beyond_prefixes() {
+        for (sig, exp, json) in [
+            (1u128, 11i8, "\"1e33\""),
+            (123_456, 11, "\"1.23456e38\""),
+            (1, -11, "\"1e-33\""),
+        ] {
+            let e1 = EQ::from_raw(sig, exp).unwrap();
+            assert_eq!(serde_json::to_string(&e1).unwrap(), json);
+            let e2: EQ<u128> = serde_json::from_str(json).unwrap();
+            assert_eq!(e1, e2);
+        }
+    }
+
+    #[test]
+    fn deserialize_float() {
+        let eq = serde_json::from_str::<EQ<i32>>("1.5").unwrap();
+        assert_eq!(eq.to_raw(), (1500, -1));
+        let eq = serde_json::from_str::<EQ<i64>>("0.001").unwrap();
+        assert_eq!(eq.to_raw(), (1, -1));
+        let eq = serde_json::from_str::<EQ<u32>>("2.5e6").unwrap();
+        assert_eq!(eq.to_raw(), (2500, 1));
+
+        let err = serde_json::from_str::<EQ<i16>>("1.0001").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value 1.0001 cannot be represented exactly in i16; it would need rounding at line 1 column 6"
+        );
+        let err = serde_json::from_str::<EQ<u16>>("-1.5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value -1.5 is negative, but u16 is unsigned at line 1 column 4"
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        fn err<T>(json: &str) -> String
+        where
+            T: crate::EQSupported<T>
+                + std::fmt::Debug
+                + std::str::FromStr
+                + TryFrom<u128>
+                + TryFrom<i128>,
+        {
+            let e = serde_json::from_str::<EQ<T>>(json).unwrap_err().to_string();
+            // strip serde_json's location suffix
+            e[..e.find(" at line").unwrap()].to_string()
+        }
+        assert_eq!(err::<u32>("\"5Q\""), "value '5Q' overflows u32 (max 4.29G)");
+        assert_eq!(
+            err::<i32>("\"-5G\""),
+            "value '-5G' overflows i32 (min -2.14G, max 2.14G)"
+        );
+        assert_eq!(
+            err::<u32>("\"-5\""),
+            "value '-5' is negative, but u32 is unsigned"
+        );
+        assert_eq!(
+            err::<i64>("\"1q\""),
+            "value '1q' underflows i64 (smallest 1a)"
+        );
+        assert_eq!(
+            err::<i16>("\"99999\""),
+            "value '99999' overflows i16 (min -32.7k, max 32.7k)"
+        );
+        assert_eq!(
+            err::<i16>("\"1.2345\""),
+            "value '1.2345' has too many significant figures for i16"
+        );
+        assert_eq!(
+            err::<u16>("\"xyz\""),
+            "invalid value: string \"xyz\", expected an engineering quantity (1M, 4.5k, etc) or a number"
+        );
+        assert_eq!(err::<u16>("70000"), "value 70000 overflows u16 (max 65.5k)");
+        assert_eq!(
+            err::<u16>("-7"),
+            "value -7 is negative, but u16 is unsigned"
+        );
+        assert_eq!(
+            err::<i16>("-70000"),
+            "value -70000 overflows i16 (min -32.7k, max 32.7k)"
+        );
+    }
+
     #[test]
     fn type_mismatch() {
         let _ = serde_json::from_str::<EQ<i32>>("false").expect_err("type mismatch");
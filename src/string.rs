@@ -1,64 +1,264 @@
 //! String conversions
 
-use std::{
-    cmp::{min, Ordering},
-    fmt::Display,
-    str::FromStr,
-};
+use std::{borrow::Cow, cmp::min, fmt::Display, str::FromStr};
 
-use crate::{EQSupported, EngineeringQuantity, Error};
+use crate::{EQSupported, EngineeringQuantity, Error, Grouping, PartsPer, Prefix, PrefixSet};
 
-static POSITIVE_MULTIPLIERS: &str = " kMGTPEZYRQ";
-static NEGATIVE_MULTIPLIERS: &str = " munpfazyrq"; // μ is not ASCII, which confounds things a little
+/// Returns the SI prefix symbol for the given power of ten, or "" if there is none
+pub(crate) fn pow10_to_multiplier(pow10: i32) -> &'static str {
+    Prefix::from_pow10(pow10).map_or("", Prefix::symbol)
+}
+
+/// Finds the first SI prefix in a string.
+///
+/// If `non_engineering` is set, the c, d, da and h prefixes are also recognised.
+fn find_multiplier(
+    s: &str,
+    non_engineering: bool,
+) -> Option<(
+    usize, /* index */
+    usize, /* length */
+    i32,   /* power of ten */
+)> {
+    let mut chars = s.chars().enumerate().peekable();
+    while let Some((i, c)) = chars.next() {
+        match Prefix::from_char(c) {
+            Some(p) if p.is_engineering() => return Some((i, 1, p.pow10())),
+            Some(Prefix::Deci)
+                if non_engineering && chars.peek().is_some_and(|(_, c)| *c == 'a') =>
+            {
+                return Some((i, 2, Prefix::Deca.pow10()))
+            }
+            Some(p) if non_engineering => return Some((i, 1, p.pow10())),
+            _ => (),
+        }
+    }
+    None
+}
 
-fn exponent_to_multiplier(exp: i8) -> &'static str {
-    let abs = exp.unsigned_abs() as usize;
-    match (exp.cmp(&0), abs) {
-        (Ordering::Equal, _) => "",
-        (Ordering::Greater, _) => &POSITIVE_MULTIPLIERS[abs..=abs],
-        (Ordering::Less, 2) => "μ", // special case as non-ASCII
-        (Ordering::Less, _) => &NEGATIVE_MULTIPLIERS[abs..=abs],
+/// Replaces a trailing SI prefix name (case-insensitive, optionally after whitespace) with its symbol,
+/// so `4.7 Kilo` becomes `4.7k`.
+fn expand_prefix_name(s: &str) -> Cow<'_, str> {
+    let trimmed = s.trim_end();
+    let lower = trimmed.to_ascii_lowercase();
+    let Some(prefix) = Prefix::iter().find(|p| lower.ends_with(p.name())) else {
+        return Cow::Borrowed(s);
+    };
+    // The name is ASCII, so its length in bytes is the same in both strings
+    let number = trimmed[..trimmed.len() - prefix.name().len()].trim_end();
+    if number.is_empty() {
+        return Cow::Borrowed(s);
     }
+    Cow::Owned(format!("{number}{}", prefix.ascii_symbol()))
 }
 
-const fn multiplier_to_exponent(prefix: char) -> Option<i8> {
-    Some(match prefix {
-        //' ' => 0,
-        'k' => 1,
-        'M' => 2,
-        'G' => 3,
-        'T' => 4,
-        'P' => 5,
-        'E' => 6,
-        'Z' => 7,
-        'Y' => 8,
-        'R' => 9,
-        'Q' => 10,
-        'm' => -1,
-        'μ' | 'u' => -2,
-        'n' => -3,
-        'p' => -4,
-        'f' => -5,
-        'a' => -6,
-        'z' => -7,
-        'y' => -8,
-        'r' => -9,
-        'q' => -10,
-        _ => return None,
+/// A number string, split into its components by [`parse_decimal()`]
+pub(crate) struct ParsedDecimal {
+    /// Decimal digits, possibly with a sign. These have not been validated.
+    pub(crate) digits: String,
+    /// The power of ten to apply to the digits
+    pub(crate) pow10: i32,
+    /// The power of ten of the SI prefix found in the string, if any
+    pub(crate) prefix: Option<i32>,
+}
+
+impl ParsedDecimal {
+    /// Does the string contain digits, without an SI prefix?
+    ///
+    /// A name or unit alone is not a number, even though padding its empty digits to a multiple of 3 would make one.
+    pub(crate) fn is_plain_number(&self) -> bool {
+        self.prefix.is_none() && self.digits.bytes().any(|b| b.is_ascii_digit())
+    }
+}
+
+/// Removes a suffix from a string, ignoring ASCII case
+pub(crate) fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let index = s.len().checked_sub(suffix.len())?;
+    s.get(index..)?
+        .eq_ignore_ascii_case(suffix)
+        .then(|| &s[..index])
+}
+
+/// Parses a number in E-notation (`1.5e33`), as written for numbers beyond the range of the SI prefixes.
+///
+/// The exponent marker is a lower case `e`, as upper case `E` is the exa prefix.
+fn parse_scientific(s: &str) -> Option<ParsedDecimal> {
+    let (mantissa, exponent) = s.trim().split_once('e')?;
+    let exponent = exponent.parse::<i32>().ok()?;
+    let parsed = parse_decimal(mantissa, false);
+    parsed.is_plain_number().then(|| ParsedDecimal {
+        pow10: parsed.pow10.saturating_add(exponent),
+        ..parsed
     })
 }
 
-fn find_multiplier(s: &str) -> Option<(usize /* index */, i8 /* exponent */)> {
-    for (i, c) in s.chars().enumerate() {
-        if let Some(p) = multiplier_to_exponent(c) {
-            return Some((i, p));
+/// Splits a string into a (signed) string of decimal digits and a power of ten.
+///
+/// If `non_engineering` is set, the c, d, da and h prefixes are also recognised.
+/// The prefix may also be given as a full name after the number (`4.7 kilo`),
+/// and numbers beyond the range of the prefixes may be written in E-notation (`1.5e33`).
+/// The digits are not validated; that is left to the integer conversion.
+pub(crate) fn parse_decimal(s: &str, non_engineering: bool) -> ParsedDecimal {
+    if let Some(parsed) = parse_scientific(s) {
+        return parsed;
+    }
+    let s = &*expand_prefix_name(s);
+    let prefix = find_multiplier(s, non_engineering);
+    // Is there a decimal? If so it's standard (non RKM) mode.
+    let decimal = s.find('.');
+    let (split_index, skip, prefix_pow10) = match (prefix, decimal) {
+        // Easy case: direct integer conversion
+        (None, None) => {
+            return ParsedDecimal {
+                digits: s.to_string(),
+                pow10: 0,
+                prefix: None,
+            }
+        }
+        // 1.23 (no multiplier suffix)
+        (None, Some(idx)) => (idx, 1, 0),
+        // Non-RKM mode (1.5k)
+        (Some((_, _, p10)), Some(idx)) => (idx, 1, p10),
+        // RKM mode (1k5)
+        (Some((idx, len, p10)), None) => (idx, len, p10),
+    };
+
+    let mut to_convert = s.chars().take(split_index).collect::<String>();
+    let mut trailing = s.chars().skip(split_index + skip).collect::<String>();
+
+    // In non-RKM mode, don't convert the prefix (err, the suffix)
+    if let (Some(_), Some((_, len, _))) = (decimal, prefix) {
+        for _ in 0..len {
+            let _ = trailing.pop();
         }
     }
-    None
+
+    // Each digit after the point is another power of ten.
+    to_convert.push_str(&trailing);
+    let trailing_len = i32::try_from(trailing.len()).unwrap_or(i32::MAX);
+    ParsedDecimal {
+        digits: to_convert,
+        pow10: prefix_pow10 - trailing_len,
+        prefix: prefix.map(|(_, _, p10)| p10),
+    }
 }
 
-/////////////////////////////////////////////////////////////////////////
-// STRING TO NUMBER
+/// Counts the significant figures in a string of digits: all of the digits, except for leading zeroes.
+fn count_significant_figures(digits: &str) -> u8 {
+    let count = digits
+        .chars()
+        .filter(char::is_ascii_digit)
+        .skip_while(|c| *c == '0')
+        .count();
+    u8::try_from(count.max(1)).unwrap_or(u8::MAX)
+}
+
+/// Splits an integer into its sign and the decimal digits of its magnitude.
+///
+/// Unlike [`crate::SignHelper`], this works on the minimum value of a signed type.
+pub(crate) fn sign_and_digits<T: Display>(value: &T) -> (bool, String) {
+    let s = value.to_string();
+    match s.strip_prefix('-') {
+        Some(digits) => (true, digits.to_string()),
+        None => (false, s),
+    }
+}
+
+/// Converts a power of ten into an engineering exponent, reporting over/underflow
+pub(crate) fn pow10_to_exponent(pow10: i32) -> Result<i8, Error> {
+    i8::try_from(pow10.div_euclid(3)).map_err(|_| {
+        if pow10 < 0 {
+            Error::Underflow
+        } else {
+            Error::Overflow
+        }
+    })
+}
+
+/// A configurable string parser for [`EngineeringQuantity`].
+///
+/// The default parser is used by the [`FromStr`] implementation.
+/// ```
+/// use engineering_repr::{EngineeringQuantity as EQ, Error, Parser, PrefixSet};
+/// let parser = Parser {
+///     prefixes: PrefixSet::all().without(-1), // micro is fine, but no milli
+///     ..Default::default()
+/// };
+/// let q: EQ<i64> = parser.parse("1500μ").unwrap();
+/// assert_eq!(q.to_raw(), (1500, -2));
+/// assert_eq!(parser.parse::<i64>("1.5m"), Err(Error::DisallowedPrefix("m")));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Parser {
+    /// The SI prefixes which are accepted
+    pub prefixes: PrefixSet,
+    /// If set, numbers are written with this system of named multipliers instead of SI prefixes,
+    /// and `prefixes` is ignored.
+    pub grouping: Option<Grouping>,
+}
+
+impl Parser {
+    /// Parses a string into an [`EngineeringQuantity`].
+    ///
+    /// Standard (`1.5k`) and RKM (`1k5`) notation are both accepted,
+    /// as are dimensionless ratios (`0.1%`, `20ppm`; see [`PartsPer`]).
+    ///
+    /// The c, d, da and h prefixes are only recognised if they are in the allowed set.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, Parser, PrefixSet};
+    /// let parser = Parser {
+    ///     prefixes: PrefixSet::all().union(PrefixSet::non_engineering()),
+    ///     ..Default::default()
+    /// };
+    /// let q: EQ<i64> = parser.parse("2.5c").unwrap();
+    /// assert_eq!(q.to_raw(), (25, -1));
+    /// let q: EQ<i64> = parser.parse("1da5").unwrap();
+    /// assert_eq!(q.to_raw(), (15, 0));
+    /// ```
+    pub fn parse<T: EQSupported<T> + FromStr>(
+        &self,
+        s: &str,
+    ) -> Result<EngineeringQuantity<T>, Error> {
+        if let Some(parsed) = PartsPer::parse(s) {
+            let parsed = parsed?;
+            return from_digits(parsed.digits, parsed.pow10);
+        }
+        if let Some(grouping) = self.grouping {
+            let parsed = grouping.parse(s)?;
+            return from_digits(parsed.digits, parsed.pow10);
+        }
+        let ParsedDecimal {
+            digits: to_convert,
+            pow10,
+            prefix,
+        } = parse_decimal(s, self.prefixes.has_non_engineering());
+        let prefix = prefix.unwrap_or(0);
+        if !self.prefixes.contains_pow10(prefix) {
+            return Err(Error::DisallowedPrefix(pow10_to_multiplier(prefix)));
+        }
+        from_digits(to_convert, pow10)
+    }
+}
+
+/// Converts a (signed) string of decimal digits and a power of ten into a quantity,
+/// recording the number of significant figures.
+pub(crate) fn from_digits<T: EQSupported<T> + FromStr>(
+    mut digits: String,
+    pow10: i32,
+) -> Result<EngineeringQuantity<T>, Error> {
+    let significant_figures = count_significant_figures(&digits);
+    // Each 3 digits (or part thereof) represents another exponent.
+    // If it's not a round multiple of 3, we need to pad !
+    for _ in 0..pow10.rem_euclid(3) {
+        digits.push('0');
+    }
+    let exponent = pow10_to_exponent(pow10)?;
+    let significand = T::from_str(&digits).map_err(|_| Error::ParseError)?;
+    Ok(EngineeringQuantity {
+        significant_figures: Some(significant_figures),
+        ..EngineeringQuantity::from_raw(significand, exponent)?
+    })
+}
 
 impl<T: EQSupported<T> + FromStr> FromStr for EngineeringQuantity<T> {
     type Err = Error;
@@ -74,62 +274,7 @@ impl<T: EQSupported<T> + FromStr> FromStr for EngineeringQuantity<T> {
     /// assert_eq!(eq, eq2);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let prefix = find_multiplier(s);
-        // Is there a decimal? If so it's standard (non RKM) mode.
-        let decimal = s.find('.');
-        let (prefix_index, exponent) = match (prefix, decimal) {
-            // Easy case: direct integer conversion
-            (None, None) => {
-                return T::from_str(s)
-                    .map_err(|_| Error::ParseError)
-                    .and_then(|i| EngineeringQuantity::from_raw(i, 0));
-            }
-            // 1.23 (no multiplier suffix)
-            (None, Some(idx)) => (idx, 0),
-            // General case
-            (Some((id, exp)), _) => (id, exp),
-        };
-
-        let split_index = if let Some(d) = decimal {
-            // Non-RKM mode (1.5k)
-            d
-        } else {
-            // RKM mode (1k5)
-            prefix_index
-        };
-
-        let mut to_convert = s.chars().take(split_index).collect::<String>();
-        let mut trailing = s.chars().skip(split_index + 1).collect::<String>();
-
-        // In non-RKM mode, don't convert the prefix (err, the suffix)
-        if decimal.is_some() && prefix.is_some() {
-            let _ = trailing.pop();
-        }
-
-        // Each 3 digits (or part thereof) represents another exponent.
-        to_convert.push_str(&trailing);
-        // If it's not a round multiple of 3, we need to pad !
-        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-        let whole_groups = (trailing.len() / 3) as i8;
-        // convert to signed so we can trap a panic
-        #[allow(clippy::cast_possible_wrap)]
-        let mut exponent = exponent;
-        match trailing.len() % 3 {
-            0 => exponent -= whole_groups,
-            n => {
-                // n must be 1 or 2
-                exponent -= whole_groups + 1;
-                to_convert.push_str("0".repeat(3 - n).as_str());
-            }
-        }
-
-        let significand = T::from_str(&to_convert).map_err(|_| Error::ParseError)?;
-        #[allow(
-            clippy::cast_possible_truncation,
-            clippy::cast_possible_wrap,
-            clippy::cast_sign_loss
-        )]
-        Self::from_raw(significand, exponent)
+        Parser::default().parse(s)
     }
 }
 
@@ -150,7 +295,7 @@ impl<T: EQSupported<T>> Display for EngineeringQuantity<T> {
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         DisplayAdapter {
-            value: *self,
+            value: self.clone(),
             ..Default::default()
         }
         .fmt(f)
@@ -162,33 +307,147 @@ impl<T: EQSupported<T>> Display for EngineeringQuantity<T> {
 ///
 /// This type may be conveniently created by [`EngineeringQuantity::with_precision()`]
 /// and [`EngineeringQuantity::rkm_with_precision()`].
+/// Formatting options may be added in future, so it cannot be created with a struct literal;
+/// start from one of those methods, or from [`Default`], and set the fields you need.
 #[derive(Copy, Clone, Debug)]
+#[allow(clippy::struct_excessive_bools)] // the formatting options are independent
+#[non_exhaustive]
 pub struct DisplayAdapter<T: EQSupported<T>>
 where
     T: ToString,
 {
     /// The value to be displayed
     pub value: EngineeringQuantity<T>,
-    /// The precision at which to display, or 0 to work it out losslessly
+    /// The precision at which to display, or 0 to work it out losslessly.
+    ///
+    /// This limits the digits after the decimal point (or multiplier, in RKM mode); digits before it are always output.
+    /// An automatically chosen prefix leaves at most 3 of them, but a pinned prefix (see `exponent_range`) may leave more.
     pub max_significant_figures: usize,
     /// Specifies [RKM code](https://en.wikipedia.org/wiki/RKM_code) mode
     pub rkm: bool,
     /// Always emit the precision requested, even any unnecessary untrailing zeroes after the decimal point.
     pub strict: bool,
+    /// If set, the precision is a number of digits after the decimal point (or multiplier, in RKM mode),
+    /// and `max_significant_figures` is ignored.
+    pub decimal_places: Option<usize>,
+    /// If set, the output exponent (in powers of 1000, like [`EngineeringQuantity::to_raw()`]) is constrained to this
+    /// inclusive range, instead of being chosen automatically. Exponents beyond q (-10) or Q (+10) are not supported.
+    pub exponent_range: Option<(i8, i8)>,
+    /// The SI prefixes which may be output. The best allowed prefix is chosen:
+    /// the largest which does not exceed the number or, failing that, the smallest.
+    ///
+    /// The c, d, da and h prefixes are only output if they are in this set.
+    pub prefixes: PrefixSet,
+    /// Writes the prefix as its full name after a space (`4.7 kilo`), instead of as a symbol.
+    /// RKM mode does not apply.
+    pub long_names: bool,
+    /// If set, the multiplier is written with this system of named multipliers, instead of an SI prefix.
+    /// `prefixes`, `long_names` and RKM mode do not apply.
+    pub grouping: Option<Grouping>,
+    /// A unit to write after the number and its multiplier, for example `s`
+    pub unit: &'static str,
 }
 
 impl<T: EQSupported<T>> Default for DisplayAdapter<T> {
     fn default() -> Self {
         Self {
-            value: EngineeringQuantity {
-                significand: T::ZERO,
-                exponent: 0,
-            },
+            value: EngineeringQuantity::from_raw_unchecked(T::ZERO, 0),
             max_significant_figures: 3,
             rkm: false,
             strict: false,
+            decimal_places: None,
+            exponent_range: None,
+            prefixes: PrefixSet::all(),
+            long_names: false,
+            grouping: None,
+            unit: "",
+        }
+    }
+}
+
+impl<T: EQSupported<T>> DisplayAdapter<T> {
+    /// Pins the output to the SI prefix with the given exponent (in powers of 1000).
+    ///
+    /// The output will have leading `0.` digits, or extra integer digits, as necessary.
+    /// As elsewhere, the precision only limits the digits after the decimal point: integer digits are never dropped,
+    /// so a number with more integer digits than the precision is written with all of them.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let values = [470, 12_000, 1_500_000].map(|i| EQ::<i32>::from(i).with_precision(3).with_fixed_exponent(1).to_string());
+    /// assert_eq!(values, ["0.47k", "12k", "1500k"]);
+    /// assert_eq!(EQ::<i32>::from(1_234_567).with_precision(3).with_fixed_exponent(1).to_string(), "1234k");
+    /// ```
+    #[must_use]
+    pub fn with_fixed_exponent(self, exponent: i8) -> Self {
+        self.with_exponent_range(exponent, exponent)
+    }
+    /// Constrains the output to the SI prefixes with exponents (in powers of 1000) between `min` and `max` inclusive.
+    ///
+    /// Within this range, the prefix is chosen as usual.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let values = [470, 12_000, 1_500_000_000].map(|i| EQ::<i32>::from(i).with_precision(3).with_exponent_range(1, 2).to_string());
+    /// assert_eq!(values, ["0.47k", "12k", "1500M"]);
+    /// ```
+    #[must_use]
+    pub fn with_exponent_range(self, min: i8, max: i8) -> Self {
+        Self {
+            exponent_range: Some((min, max)),
+            ..self
+        }
+    }
+    /// Restricts the SI prefixes which may be output.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, PrefixSet};
+    /// let no_milli = PrefixSet::all().without(-1);
+    /// let q = EQ::<i32>::from_raw(1500, -2).unwrap();
+    /// assert_eq!(q.with_precision(0).to_string(), "1.5m");
+    /// assert_eq!(q.with_precision(0).with_prefixes(no_milli).to_string(), "1500μ");
+    /// ```
+    #[must_use]
+    pub fn with_prefixes(self, prefixes: PrefixSet) -> Self {
+        Self { prefixes, ..self }
+    }
+    /// Writes the prefix as its full name, for example for screen readers.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::<i32>::from(4700);
+    /// assert_eq!(q.with_precision(2).with_long_names().to_string(), "4.7 kilo");
+    /// assert_eq!(format!("{}ohms", q.with_precision(2).with_long_names()), "4.7 kiloohms");
+    /// assert_eq!(EQ::<i32>::from(47).with_precision(2).with_long_names().to_string(), "47");
+    /// ```
+    #[must_use]
+    pub fn with_long_names(self) -> Self {
+        Self {
+            long_names: true,
+            ..self
         }
     }
+    /// Writes the multiplier with a system of named multipliers, instead of an SI prefix.
+    /// ```
+    /// use engineering_repr::{EngineeringQuantity as EQ, Grouping};
+    /// let q = EQ::<i64>::from(2_500_000_000i64);
+    /// assert_eq!(q.with_precision(2).with_grouping(Grouping::ShortScale).to_string(), "2.5 billion");
+    /// assert_eq!(q.with_precision(2).with_grouping(Grouping::ShortScaleAbbreviated).to_string(), "2.5B");
+    /// ```
+    #[must_use]
+    pub fn with_grouping(self, grouping: Grouping) -> Self {
+        Self {
+            grouping: Some(grouping),
+            ..self
+        }
+    }
+    /// Writes a unit after the number and its multiplier.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let q = EQ::<i32>::from(4700);
+    /// assert_eq!(q.with_precision(2).with_unit("Ω").to_string(), "4.7kΩ");
+    /// assert_eq!(q.rkm_with_precision(2).with_unit("Ω").to_string(), "4k7Ω");
+    /// ```
+    #[must_use]
+    pub fn with_unit(self, unit: &'static str) -> Self {
+        Self { unit, ..self }
+    }
 }
 
 impl<T: EQSupported<T>> PartialEq<DisplayAdapter<T>> for &str {
@@ -209,10 +468,9 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     #[must_use]
     pub fn with_precision(&self, max_significant_figures: usize) -> DisplayAdapter<T> {
         DisplayAdapter {
-            value: *self,
+            value: self.clone(),
             max_significant_figures,
-            rkm: false,
-            strict: false,
+            ..Default::default()
         }
     }
     /// Creates an RKM [`DisplayAdapter`] for this object in RKM mode, with the given precision.
@@ -224,10 +482,10 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     #[must_use]
     pub fn rkm_with_precision(&self, max_significant_figures: usize) -> DisplayAdapter<T> {
         DisplayAdapter {
-            value: *self,
+            value: self.clone(),
             max_significant_figures,
             rkm: true,
-            strict: false,
+            ..Default::default()
         }
     }
     /// Creates a [`DisplayAdapter`] for this object, with strict precision.
@@ -240,16 +498,156 @@ impl<T: EQSupported<T>> EngineeringQuantity<T> {
     #[must_use]
     pub fn with_strict_precision(&self, max_significant_figures: usize) -> DisplayAdapter<T> {
         DisplayAdapter {
-            value: *self,
+            value: self.clone(),
             max_significant_figures,
-            rkm: false,
             strict: true,
+            ..Default::default()
+        }
+    }
+    /// Creates a [`DisplayAdapter`] for this object, with the given number of decimal places.
+    /// Unnecessary trailing zeroes are not output.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let ee = EQ::<i32>::from(1_234_567);
+    /// assert_eq!(ee.with_decimal_places(2).to_string(), "1.23M");
+    /// assert_eq!(EQ::<i32>::from(1_500).with_decimal_places(2).to_string(), "1.5k");
+    /// ```
+    #[must_use]
+    pub fn with_decimal_places(&self, decimal_places: usize) -> DisplayAdapter<T> {
+        DisplayAdapter {
+            value: self.clone(),
+            decimal_places: Some(decimal_places),
+            ..Default::default()
+        }
+    }
+    /// Creates a [`DisplayAdapter`] for this object, with exactly the given number of decimal places.
+    /// The requested digits will always be output, even trailing zeroes.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// assert_eq!(EQ::<i32>::from(1_500).with_strict_decimal_places(2).to_string(), "1.50k");
+    /// assert_eq!(EQ::<i32>::from(12_000).with_strict_decimal_places(2).to_string(), "12.00k");
+    /// ```
+    #[must_use]
+    pub fn with_strict_decimal_places(&self, decimal_places: usize) -> DisplayAdapter<T> {
+        DisplayAdapter {
+            value: self.clone(),
+            decimal_places: Some(decimal_places),
+            strict: true,
+            ..Default::default()
+        }
+    }
+    /// Creates an RKM [`DisplayAdapter`] for this object, with the given number of digits after the multiplier.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// let ee = EQ::<i32>::from(1_234_567);
+    /// assert_eq!(ee.rkm_with_decimal_places(2).to_string(), "1M23");
+    /// ```
+    #[must_use]
+    pub fn rkm_with_decimal_places(&self, decimal_places: usize) -> DisplayAdapter<T> {
+        DisplayAdapter {
+            value: self.clone(),
+            decimal_places: Some(decimal_places),
+            rkm: true,
+            ..Default::default()
+        }
+    }
+    /// Creates a [`DisplayAdapter`] for this object which reproduces the precision of the string it was parsed from.
+    ///
+    /// If the quantity was not parsed from a string, this is the same as the default output format.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use std::str::FromStr as _;
+    /// let ee = EQ::<i32>::from_str("1.00M").unwrap();
+    /// assert_eq!(ee.to_string(), "1M");
+    /// assert_eq!(ee.with_original_precision().to_string(), "1.00M");
+    /// ```
+    #[must_use]
+    pub fn with_original_precision(&self) -> DisplayAdapter<T> {
+        match self.significant_figures() {
+            Some(n) => self.with_strict_precision(n),
+            None => self.with_precision(3),
+        }
+    }
+    /// Creates an RKM [`DisplayAdapter`] for this object which reproduces the precision of the string it was parsed from.
+    ///
+    /// If the quantity was not parsed from a string, this is the same as the default output format, in RKM mode.
+    /// ```
+    /// use engineering_repr::EngineeringQuantity as EQ;
+    /// use std::str::FromStr as _;
+    /// let ee = EQ::<i32>::from_str("4.70k").unwrap();
+    /// assert_eq!(ee.rkm_with_original_precision().to_string(), "4k70");
+    /// ```
+    #[must_use]
+    pub fn rkm_with_original_precision(&self) -> DisplayAdapter<T> {
+        DisplayAdapter {
+            rkm: true,
+            ..self.with_original_precision()
         }
     }
 }
 
 impl<T: EQSupported<T>> Display for DisplayAdapter<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (negative, digits) = sign_and_digits(&self.value.significand);
+        Style {
+            max_significant_figures: self.max_significant_figures,
+            rkm: self.rkm,
+            strict: self.strict,
+            decimal_places: self.decimal_places,
+            exponent_range: self.exponent_range,
+            prefixes: self.prefixes,
+            long_names: self.long_names,
+            grouping: self.grouping,
+            unit: self.unit,
+        }
+        .write(f, negative, digits, 3 * i32::from(self.value.exponent))
+    }
+}
+
+/// Formatting options, shared by the display adapters
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Style {
+    pub(crate) max_significant_figures: usize,
+    pub(crate) rkm: bool,
+    pub(crate) strict: bool,
+    pub(crate) decimal_places: Option<usize>,
+    pub(crate) exponent_range: Option<(i8, i8)>,
+    pub(crate) prefixes: PrefixSet,
+    pub(crate) long_names: bool,
+    pub(crate) grouping: Option<Grouping>,
+    pub(crate) unit: &'static str,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            max_significant_figures: 3,
+            rkm: false,
+            strict: false,
+            decimal_places: None,
+            exponent_range: None,
+            prefixes: PrefixSet::all(),
+            long_names: false,
+            grouping: None,
+            unit: "",
+        }
+    }
+}
+
+impl Style {
+    /// The most extreme powers of ten that can be written with an SI prefix (q and Q)
+    const PREFIX_POW10_RANGE: std::ops::RangeInclusive<i32> = -30..=30;
+
+    /// Outputs the number `digits` * 10 ^ `pow10`, where `digits` is a string of decimal digits.
+    ///
+    /// Numbers too large or small to write with the largest or smallest SI prefix are written in E-notation.
+    pub(crate) fn write(
+        self,
+        f: &mut std::fmt::Formatter<'_>,
+        negative: bool,
+        mut digits: String,
+        mut pow10: i32,
+    ) -> std::fmt::Result {
         /*
          * We prepare the output string in five parts:
          * - Prefix   := "-" (negative) or "" (positive)
@@ -259,67 +657,100 @@ impl<T: EQSupported<T>> Display for DisplayAdapter<T> {
          * - Suffix   := multiplier (normal mode) or "" (rkm mode)
          *
          * Algorithm:
-         * 1. Convert significand to digits
-         * 2. Compute the output exponent such that the quantity to the left of the output decimal point is from 1 to 999
-         *    (Positive exponents) Append zeroes in groups of 3 until we reach the true decimal point
-         *    (Negative exponents) Append nothing
-         * 3. Split into leading/trailing (this is a function of the exponent)
+         * 1. Find the position of the most significant digit
+         * 2. Compute the output exponent such that the quantity to the left of the output decimal point is from 1 to 999,
+         *    choosing from the allowed prefixes and constraining it to the requested range (if any)
+         * 3. Split into leading/trailing (this is a function of the exponent);
+         *    prepend or append zeroes if necessary to reach the output decimal point
          * 4. Implement precision:
          *    If precision is arbitrary, trim all trailing zeroes.
          *    Otherwise, trim trailing digits as necessary to meet the request.
          */
-        let detail = self.value.significand.abs_and_sign();
-        let mut digits = detail.abs.to_string();
-        // at first glance the output might reasonably be this value of `digits`, followed by `exponent` times "000"...
-        // but we need to (re)compute the correct exponent for display.
-        let prefix = if detail.negative { "-" } else { "" };
-        #[allow(clippy::cast_possible_truncation)]
-        let output_exponent = if self.value.exponent > 0 {
-            // Append zeroes until we reach the decimal point (we may trim some later)
-            digits.reserve((3 * self.value.exponent + 1).unsigned_abs() as usize);
-            for _ in 0..self.value.exponent {
-                digits.push_str("000");
-            }
-            ((digits.len() - 1) / 3) as i8
-        } else {
-            // Negative or zero exponent: Append nothing, but we need a different formula for the output exponent
-            self.value.exponent + ((digits.len() - 1) / 3) as i8
+        let prefix = if negative { "-" } else { "" };
+        let is_zero = digits.bytes().all(|b| b == b'0');
+        if is_zero {
+            digits = "0".into();
+            pow10 = 0;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let msd = digits.len() as i32 - 1 + pow10;
+        let available = self.grouping.map_or(self.prefixes, Grouping::pow10s);
+        let candidates = match self.exponent_range {
+            Some((lo, hi)) => available.within(lo, hi),
+            None => available,
         };
-        let si = exponent_to_multiplier(output_exponent);
+        let output_pow10 = candidates.best_pow10(msd).unwrap_or_else(|| {
+            // No allowed prefix; fall back to the requested range
+            let (lo, hi) = self.exponent_range.unwrap_or((0, 0));
+            3 * msd
+                .div_euclid(3)
+                .max(i32::from(lo.max(-10)))
+                .min(i32::from(hi.min(10)))
+        });
+        // Beyond the largest or smallest prefix, fall back to E-notation
+        if !is_zero
+            && (msd > Self::PREFIX_POW10_RANGE.end() + 2 || msd < *Self::PREFIX_POW10_RANGE.start())
+            && output_pow10.abs() == *Self::PREFIX_POW10_RANGE.end()
+        {
+            return self.write_scientific(f, prefix, &digits, pow10);
+        }
+        let si = pow10_to_multiplier(output_pow10);
 
-        let n_leading = if output_exponent > 0 {
-            digits.len() - output_exponent.unsigned_abs() as usize * 3
-        } else {
-            match digits.len() % 3 {
-                0 => 3,
-                i => i,
+        let mut n_leading = msd - output_pow10 + 1;
+        // If the number is less than 1 in the output unit, prepend zeroes (these are not significant)
+        let mut insignificant = 0;
+        if n_leading < 1 {
+            let zeroes = (1 - n_leading).unsigned_abs() as usize;
+            digits.insert_str(0, &"0".repeat(zeroes));
+            n_leading = 1;
+            if !is_zero {
+                insignificant = zeroes;
             }
+        }
+        #[allow(clippy::cast_sign_loss)]
+        let n_leading = n_leading as usize;
+        // Append zeroes until we reach the decimal point
+        while digits.len() < n_leading {
+            digits.push('0');
+        }
+        // number of digits we'd take after the decimal point to reach the requested precision
+        let wanted_trailing = match (self.decimal_places, self.max_significant_figures) {
+            (Some(places), _) => places,
+            (None, 0) => usize::MAX, // automatic mode: take the digits we've got from a full conversion, we'll trim trailing 0s in a moment
+            (None, i) => (i + insignificant).saturating_sub(n_leading),
         };
-        let precision = match self.max_significant_figures {
-            0 => usize::MAX, // automatic mode: take the digits we've got from a full conversion, we'll trim trailing 0s in a moment
-            i => i,
-        };
-        if self.strict {
-            let pad = self.max_significant_figures.saturating_sub(digits.len());
-            for _ in 0..pad {
+        if self.strict && wanted_trailing != usize::MAX {
+            while digits.len() < n_leading + wanted_trailing {
                 digits.push('0');
             }
         }
         let n_trailing = min(
             // number of digits remaining
             digits.len() - n_leading,
-            // number of digits we'd take to reach the requested precision
-            precision - min(precision, n_leading),
+            wanted_trailing,
         );
         let leaders = &digits[0..n_leading];
-        let mut trailers = &digits[n_leading..n_leading + min(n_trailing, precision)];
+        let mut trailers = &digits[n_leading..n_leading + n_trailing];
         if !self.strict {
             while trailers.ends_with('0') {
                 trailers = &trailers[0..trailers.len() - 1];
             }
         }
+        let named = match self.grouping {
+            Some(grouping) => grouping.suffix(output_pow10),
+            None if self.long_names => Prefix::from_pow10(output_pow10).map(|p| (" ", p.name())),
+            None => None,
+        };
+        if let (Some((separator, name)), false) = (named, output_pow10 == 0) {
+            let point = if trailers.is_empty() { "" } else { "." };
+            let unit = self.unit;
+            return write!(
+                f,
+                "{prefix}{leaders}{point}{trailers}{separator}{name}{unit}"
+            );
+        }
         // Point and suffix strings resolve to a 3-boolean truth table...
-        let (point, suffix) = match (output_exponent == 0, self.rkm, trailers.is_empty()) {
+        let (point, suffix) = match (output_pow10 == 0, self.rkm, trailers.is_empty()) {
             // Output exponent is 0: mode is irrelevant, no suffix, suppress point if there are no digits after it
             (true, _, true) => ("", ""),
             (true, _, false) => (".", ""),
@@ -330,7 +761,41 @@ impl<T: EQSupported<T>> Display for DisplayAdapter<T> {
             (false, false, true) => ("", si), // No trailer, suppress point
             (false, false, false) => (".", si), // With trailer, output point
         };
-        write!(f, "{prefix}{leaders}{point}{trailers}{suffix}")
+        let unit = self.unit;
+        write!(f, "{prefix}{leaders}{point}{trailers}{suffix}{unit}")
+    }
+
+    /// Outputs the (non-zero) number `digits` * 10 ^ `pow10` in E-notation, e.g. `1.5e33`.
+    ///
+    /// Precision is applied as for SI output; RKM mode, prefixes and exponent range do not apply.
+    fn write_scientific(
+        self,
+        f: &mut std::fmt::Formatter<'_>,
+        prefix: &str,
+        digits: &str,
+        pow10: i32,
+    ) -> std::fmt::Result {
+        let digits = digits.trim_start_matches('0');
+        let (leader, rest) = digits.split_at(1);
+        let exponent = i32::try_from(rest.len()).map_or(i32::MAX, |n| n.saturating_add(pow10));
+        let wanted_trailing = match (self.decimal_places, self.max_significant_figures) {
+            (Some(places), _) => places,
+            (None, 0) => usize::MAX,
+            (None, i) => i.saturating_sub(1),
+        };
+        let mut trailers: String = rest.chars().take(wanted_trailing).collect();
+        if self.strict && wanted_trailing != usize::MAX {
+            while trailers.len() < wanted_trailing {
+                trailers.push('0');
+            }
+        } else if !self.strict {
+            while trailers.ends_with('0') {
+                let _ = trailers.pop();
+            }
+        }
+        let point = if trailers.is_empty() { "" } else { "." };
+        let unit = self.unit;
+        write!(f, "{prefix}{leader}{point}{trailers}e{exponent}{unit}")
     }
 }
 
@@ -384,7 +849,7 @@ macro_rules! impl_to_eng {
     )+}
 }
 
-impl_to_eng!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+impl_to_eng!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 /////////////////////////////////////////////////////////////////////////
 
@@ -641,9 +1106,411 @@ mod test {
             assert_eq!(&ss2[1..], *s, "input={}", -*i);
         }
     }
+    #[test]
+    fn decimal_places() {
+        for (i, e, places, sloppy, strict, rkm, rkm_strict) in &[
+            (1_500i64, 0i8, 2usize, "1.5k", "1.50k", "1k5", "1k50"),
+            (12, 1, 2, "12k", "12.00k", "12k", "12k00"),
+            (123_456, 0, 1, "123.4k", "123.4k", "123k4", "123k4"),
+            (123_456, 0, 0, "123k", "123k", "123k", "123k"),
+            (470, -1, 2, "470m", "470.00m", "470m", "470m00"),
+            (1, 0, 3, "1", "1.000", "1", "1.000"),
+            (0, 0, 2, "0", "0.00", "0", "0.00"),
+            (
+                -1_234_567, 0, 4, "-1.2345M", "-1.2345M", "-1M2345", "-1M2345",
+            ),
+            (1_000_001, -2, 5, "1", "1.00000", "1", "1.00000"),
+        ] {
+            let ee = EQ::from_raw(*i, *e).unwrap();
+            assert_eq!(ee.with_decimal_places(*places).to_string(), *sloppy);
+            assert_eq!(ee.with_strict_decimal_places(*places).to_string(), *strict);
+            assert_eq!(ee.rkm_with_decimal_places(*places).to_string(), *rkm);
+            let adapter = super::DisplayAdapter {
+                strict: true,
+                ..ee.rkm_with_decimal_places(*places)
+            };
+            assert_eq!(adapter.to_string(), *rkm_strict);
+        }
+    }
+
+    #[test]
+    fn fixed_exponent() {
+        for (i, e, fixed, sloppy, strict, rkm) in &[
+            (470i128, 0i8, 1i8, "0.47k", "0.470k", "0k47"),
+            (12_000, 0, 1, "12k", "12.0k", "12k"),
+            (1_500_000, 0, 1, "1500k", "1500k", "1500k"),
+            // precision applies after the decimal point; integer digits are never dropped
+            (1_234_567, 0, 1, "1234k", "1234k", "1234k"),
+            (987_654_321, 0, 1, "987654k", "987654k", "987654k"),
+            (5, 0, 2, "0.000005M", "0.00000500M", "0M000005"),
+            (0, 0, 1, "0k", "0.00k", "0k"),
+            (47, -1, 0, "0.047", "0.0470", "0.047"),
+            (-47, -1, -2, "-47000μ", "-47000μ", "-47000μ"),
+            (1, 10, 10, "1Q", "1.00Q", "1Q"),
+        ] {
+            let ee = EQ::from_raw(*i, *e).unwrap();
+            let adapter = ee.with_precision(3).with_fixed_exponent(*fixed);
+            assert_eq!(adapter.to_string(), *sloppy, "inputs {i}, {e}");
+            let adapter = ee.with_strict_precision(3).with_fixed_exponent(*fixed);
+            assert_eq!(adapter.to_string(), *strict, "inputs {i}, {e}");
+            let adapter = ee.rkm_with_precision(3).with_fixed_exponent(*fixed);
+            assert_eq!(adapter.to_string(), *rkm, "inputs {i}, {e}");
+        }
+        // Decimal places
+        let ee = EQ::from_raw(470i64, 0).unwrap();
+        let adapter = ee.with_strict_decimal_places(2).with_fixed_exponent(1);
+        assert_eq!(adapter.to_string(), "0.47k");
+        let adapter = ee.with_strict_decimal_places(4).with_fixed_exponent(1);
+        assert_eq!(adapter.to_string(), "0.4700k");
+    }
+
+    #[test]
+    fn exponent_range() {
+        for (i, s) in &[
+            (1i64, "0.001k"),
+            (999, "0.999k"),
+            (1000, "1k"),
+            (1_000_000, "1M"),
+            (1_000_000_000, "1000M"),
+            (-1_000_000_000, "-1000M"),
+        ] {
+            let ee = EQ::<i64>::from(*i);
+            assert_eq!(
+                ee.with_precision(0).with_exponent_range(1, 2).to_string(),
+                *s
+            );
+        }
+        // Out of range requests are constrained to the prefixes we know about
+        let ee = EQ::<i64>::from(1_000);
+        assert_eq!(
+            ee.with_precision(0).with_fixed_exponent(11).to_string(),
+            "0.000000000000000000000000001Q"
+        );
+        assert_eq!(
+            ee.with_precision(0).with_exponent_range(5, 2).to_string(),
+            "0.001M"
+        );
+    }
+
+    #[test]
+    fn prefixes() {
+        use crate::{Error, Parser, PrefixSet};
+        let no_big = PrefixSet::all().without(6).without(5);
+        for (raw, expected) in &[
+            ((1, 5), "1000T"),
+            ((1, 6), "1000000T"),
+            ((47, 4), "47T"),
+            ((47, 7), "47Z"),
+            ((12, -1), "12m"),
+        ] {
+            let ee = EQ::<i128>::from_raw(raw.0, raw.1).unwrap();
+            assert_eq!(
+                ee.with_precision(0).with_prefixes(no_big).to_string(),
+                *expected
+            );
+        }
+        // Only micro is allowed
+        let micro = PrefixSet::from_symbols("μ").unwrap();
+        let ee = EQ::<i64>::from_raw(12, -1).unwrap();
+        assert_eq!(
+            ee.with_precision(0).with_prefixes(micro).to_string(),
+            "12000μ"
+        );
+        let ee = EQ::<i64>::from(3);
+        assert_eq!(
+            ee.with_precision(0).with_prefixes(micro).to_string(),
+            "3000000μ"
+        );
+        // Combined with a range
+        let ee = EQ::<i64>::from(1_500_000);
+        assert_eq!(
+            ee.with_precision(0)
+                .with_prefixes(PrefixSet::all().without(1))
+                .with_exponent_range(0, 1)
+                .to_string(),
+            "1500000"
+        );
+
+        let parser = Parser {
+            prefixes: no_big,
+            ..Default::default()
+        };
+        assert_eq!(
+            parser.parse::<i64>("4.7P"),
+            Err(Error::DisallowedPrefix("P"))
+        );
+        assert_eq!(
+            parser.parse::<i64>("4E7"),
+            Err(Error::DisallowedPrefix("E"))
+        );
+        assert_eq!(parser.parse::<i64>("4.7T").unwrap().to_raw(), (4700, 3));
+        let parser = Parser {
+            prefixes: PrefixSet::from_symbols("k").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(parser.parse::<i64>("47"), Err(Error::DisallowedPrefix("")));
+        assert_eq!(
+            Error::DisallowedPrefix("").to_string(),
+            "A number without an SI prefix is not permitted here"
+        );
+        assert_eq!(
+            Error::DisallowedPrefix("E").to_string(),
+            "The SI prefix 'E' is not permitted here"
+        );
+    }
+
+    #[test]
+    fn non_engineering_prefixes() {
+        use crate::{Error, Parser, PrefixSet};
+        // Not recognised by default
+        for s in &["2.5c", "3d", "1h", "1da"] {
+            assert_eq!(EQ::<i64>::from_str(s), Err(Error::ParseError), "{s}");
+        }
+        let parser = Parser {
+            prefixes: PrefixSet::all().union(PrefixSet::non_engineering()),
+            ..Default::default()
+        };
+        for (s, raw) in &[
+            ("2.5c", (25, -1)),
+            ("2c5", (25, -1)),
+            ("3d", (300, -1)),
+            ("1h", (100, 0)),
+            ("1.5h", (150, 0)),
+            ("1da", (10, 0)),
+            ("1.5da", (15, 0)),
+            ("1da5", (15, 0)),
+            ("-4.7k", (-4700, 0)),
+        ] {
+            assert_eq!(parser.parse::<i64>(s).unwrap().to_raw(), *raw, "{s}");
+        }
+        let parser = Parser {
+            prefixes: PrefixSet::from_symbols("c").unwrap().with(0),
+            ..Default::default()
+        };
+        assert_eq!(parser.parse::<i64>("2.5c").unwrap().to_raw(), (25, -1));
+        assert_eq!(
+            parser.parse::<i64>("2.5d"),
+            Err(Error::DisallowedPrefix("d"))
+        );
+        assert_eq!(
+            parser.parse::<i64>("2.5m"),
+            Err(Error::DisallowedPrefix("m"))
+        );
+
+        let set = PrefixSet::all().union(PrefixSet::non_engineering());
+        for (raw, expected) in &[
+            ((25, -1), "2.5c"),
+            ((300, -1), "3d"),
+            ((1, 0), "1"),
+            ((15, 0), "1.5da"),
+            ((150, 0), "1.5h"),
+            ((1500, 0), "1.5k"),
+            ((-47, -1), "-4.7c"),
+        ] {
+            let ee = EQ::<i64>::from_raw(raw.0, raw.1).unwrap();
+            assert_eq!(
+                ee.with_precision(0).with_prefixes(set).to_string(),
+                *expected
+            );
+        }
+        let ee = EQ::<i64>::from_raw(15, 0).unwrap();
+        assert_eq!(
+            ee.rkm_with_precision(0).with_prefixes(set).to_string(),
+            "1da5"
+        );
+        // Centimetres only
+        let cm = PrefixSet::from_symbols("c").unwrap();
+        let ee = EQ::<i64>::from_raw(1234, -1).unwrap();
+        assert_eq!(ee.with_precision(0).with_prefixes(cm).to_string(), "123.4c");
+    }
+
+    #[test]
+    fn original_precision() {
+        for s in &[
+            "1", "1.0", "1.00M", "4.70k", "470", "470m", "-1.500m", "12.30μ", "100.0k", "1.000",
+        ] {
+            let ee = EQ::<i64>::from_str(s).unwrap();
+            assert_eq!(ee.with_original_precision().to_string(), *s);
+        }
+        for (s, n) in &[
+            ("0", 1),
+            ("0.00", 1),
+            ("0.0012", 2),
+            ("1k5", 2),
+            ("1200", 4),
+        ] {
+            let ee = EQ::<i64>::from_str(s).unwrap();
+            assert_eq!(ee.significant_figures(), Some(*n), "input {s}");
+        }
+        for (s, rkm) in &[("4k70", "4k70"), ("1.00M", "1M00"), ("2.2", "2.2")] {
+            let ee = EQ::<i64>::from_str(s).unwrap();
+            assert_eq!(ee.rkm_with_original_precision().to_string(), *rkm);
+        }
+        // Precision survives storage conversion
+        let ee = EQ::<i32>::from_str("2.50k").unwrap();
+        assert_eq!(
+            ee.convert::<i64>().with_original_precision().to_string(),
+            "2.50k"
+        );
+        assert_eq!(
+            ee.try_convert::<i16>().unwrap().significant_figures(),
+            Some(3)
+        );
+    }
+
     #[test]
     fn strict_precision() {
         let ee = EQ::<i64>::from_raw(1234, -3).unwrap();
         assert_eq!(ee.with_strict_precision(6).to_string(), "1.23400μ");
     }
+
+    #[test]
+    fn minimum_values() {
+        assert_eq!(EQ::<i32>::from(i32::MIN).to_string(), "-2.14G");
+        assert_eq!(
+            EQ::<i128>::from(i128::MIN).with_precision(0).to_string(),
+            "-1.70141183460469231731687303715884105728e38"
+        );
+        assert_eq!(EQ::<i8>::from(i8::MIN).to_string(), "-128");
+    }
+
+    #[test]
+    fn scientific_fallback() {
+        // 1000^11 fits into a u128
+        let ee = EQ::<u128>::from_raw(1, 11).unwrap();
+        assert_eq!(ee.to_string(), "1e33");
+        let ee = EQ::<u128>::from_raw(0, 11).unwrap();
+        assert_eq!(ee.to_string(), "0");
+        // ... unless the output has been explicitly constrained
+        let ee = EQ::<u128>::from_raw(1, 11).unwrap();
+        assert_eq!(
+            ee.with_precision(0).with_fixed_exponent(0).to_string(),
+            "1000000000000000000000000000000000"
+        );
+        // Within range, the stored exponent does not matter
+        let ee = EQ::<u128>::from_raw(1_000_000, 8).unwrap();
+        assert_eq!(ee.to_string(), "1Q");
+        let dq = crate::DecimalQuantity::from_raw(-12_345i32, 50);
+        assert_eq!(dq.to_string(), "-1.23e54");
+        assert_eq!(dq.with_precision(0).to_string(), "-1.2345e54");
+        assert_eq!(dq.with_strict_precision(7).to_string(), "-1.234500e54");
+        let dq = crate::DecimalQuantity::from_raw(15u32, -40);
+        assert_eq!(dq.to_string(), "1.5e-39");
+        assert_eq!(dq.rkm_with_precision(0).to_string(), "1.5e-39");
+    }
+
+    #[test]
+    fn scientific_round_trip() {
+        for (sig, exp, s) in [
+            (1u128, 11i8, "1e33"),
+            (1500, 11, "1.5e36"),
+            (u128::MAX, 0, "3.40282366920938463463374607431768211455e38"),
+            (1, -11, "1e-33"),
+            (47, -12, "4.7e-35"),
+        ] {
+            let ee = EQ::from_raw(sig, exp).unwrap();
+            assert_eq!(ee.with_precision(0).to_string(), s);
+            assert_eq!(EQ::<u128>::from_str(s).unwrap(), ee, "{s}");
+        }
+        let ee = EQ::<i128>::from_str("-2.5e-31").unwrap();
+        assert_eq!(ee.to_raw(), (-250, -11));
+        assert_eq!(ee.to_string(), "-2.5e-31");
+        let dq = crate::DecimalQuantity::<u32>::from_str("1.5e-39").unwrap();
+        assert_eq!(dq.to_raw(), (15, -40));
+        // E is exa, not an exponent
+        assert_eq!(EQ::<i64>::from_str("1E3").unwrap().to_raw(), (1300, 5));
+        for s in ["e3", "1e", "1e3.5", "1ke3", "1e3k"] {
+            let _ = EQ::<i64>::from_str(s).expect_err(s);
+        }
+    }
+
+    #[test]
+    fn long_names() {
+        for (sig, exp, s) in [
+            (4700i64, 0i8, "4.7 kilo"),
+            (-2, 2, "-2 mega"),
+            (15, -2, "15 micro"),
+            (999, 0, "999"),
+            (1, 6, "1 exa"),
+        ] {
+            let ee = EQ::from_raw(sig, exp).unwrap();
+            assert_eq!(ee.with_precision(0).with_long_names().to_string(), s);
+            // ... and back again
+            assert_eq!(EQ::<i64>::from_str(s).unwrap(), ee, "{s}");
+        }
+        // RKM does not apply
+        let ee = EQ::<i32>::from(4700);
+        assert_eq!(
+            ee.rkm_with_precision(2).with_long_names().to_string(),
+            "4.7 kilo"
+        );
+        for s in ["4.7kilo", "4.7 KILO", "4.7 Kilo ", "4k7", "4.7k"] {
+            assert_eq!(EQ::<i32>::from_str(s).unwrap(), ee, "{s}");
+        }
+        for s in ["kilo", "4.7 kilokilo", "4.7 kilox"] {
+            let _ = EQ::<i32>::from_str(s).expect_err(s);
+        }
+        // The non-engineering prefixes must be enabled, as for their symbols
+        let _ = EQ::<i32>::from_str("25 centi").expect_err("not enabled");
+        let parser = crate::Parser {
+            prefixes: crate::PrefixSet::all().union(crate::PrefixSet::non_engineering()),
+            ..Default::default()
+        };
+        assert_eq!(parser.parse::<i32>("25 Centi").unwrap().to_raw(), (250, -1));
+        assert_eq!(parser.parse::<i32>("2 deca").unwrap().to_raw(), (20, 0));
+    }
+
+    mod properties {
+        use proptest::prelude::*;
+        use std::str::FromStr as _;
+
+        use crate::{DecimalQuantity, EngineeringQuantity as EQ};
+
+        fn all_styles<T: crate::EQSupported<T>>(ee: &EQ<T>) -> [String; 5] {
+            [
+                ee.to_string(),
+                ee.with_precision(0).to_string(),
+                ee.rkm_with_precision(2).to_string(),
+                ee.with_strict_precision(5).to_string(),
+                ee.with_precision(3)
+                    .with_exponent_range(i8::MIN, i8::MAX)
+                    .to_string(),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn display_is_total_i64(sig in any::<i64>(), exp in any::<i8>()) {
+                if let Ok(ee) = EQ::from_raw(sig, exp) {
+                    let _ = all_styles(&ee);
+                    // Lossless output parses back to the same value
+                    let s = ee.with_precision(0).to_string();
+                    prop_assert_eq!(EQ::<i64>::from_str(&s).unwrap(), ee);
+                }
+            }
+
+            #[test]
+            fn display_is_total_u128(sig in any::<u128>(), exp in any::<i8>()) {
+                if let Ok(ee) = EQ::from_raw(sig, exp) {
+                    let _ = all_styles(&ee);
+                    // ... including beyond Q, which is written in E-notation
+                    let s = ee.with_precision(0).to_string();
+                    prop_assert_eq!(EQ::<u128>::from_str(&s).unwrap(), ee);
+                }
+            }
+
+            #[test]
+            fn display_is_total_i8(sig in any::<i8>(), exp in any::<i8>()) {
+                if let Ok(ee) = EQ::from_raw(sig, exp) {
+                    let _ = all_styles(&ee);
+                }
+            }
+
+            #[test]
+            fn display_is_total_decimal(sig in any::<i32>(), exp in any::<i8>()) {
+                let dq = DecimalQuantity::from_raw(sig, exp);
+                let _ = (dq.to_string(), dq.with_precision(0).to_string(), dq.rkm_with_precision(3).to_string());
+            }
+        }
+    }
 }
@@ -0,0 +1,450 @@
+//! Sweep generators (linear, logarithmic and E-series steps)
+
+use std::iter::FusedIterator;
+
+use crate::string::sign_and_digits;
+use crate::{DecimalQuantity, EQSupported, EngineeringQuantity, Error};
+
+/////////////////////////////////////////////////////////////////////////
+// HELPERS
+
+impl<T: EQSupported<T>> EngineeringQuantity<T> {
+    /// Internal accessor: the significand, rescaled to a lower (or equal) exponent
+    fn significand_at(self, exponent: i8) -> Result<T, Error> {
+        let diff = usize::from(self.exponent.abs_diff(exponent));
+        if exponent > self.exponent {
+            return Err(Error::ImpreciseConversion);
+        }
+        crate::base_pow::<T>(diff)
+            .and_then(|factor| self.significand.checked_mul(&factor))
+            .ok_or(Error::Overflow)
+    }
+
+    /// Internal accessor: the power of ten of the most significant digit
+    fn decade(&self) -> i32 {
+        let digits = sign_and_digits(&self.significand).1.len();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let digits = digits as i32;
+        digits - 1 + 3 * i32::from(self.exponent)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// LINEAR
+
+/// An iterator over evenly-spaced quantities.
+///
+/// The sweep starts at `start` and proceeds in steps of `increment` for as long as
+/// the result does not pass `stop`. All arithmetic is performed exactly on integers,
+/// so there is no accumulation of rounding error.
+///
+/// ```
+/// use engineering_repr::{EngineeringQuantity as EQ, LinearSweep};
+/// use std::str::FromStr as _;
+/// let sweep = LinearSweep::new(
+///     EQ::<u32>::from_str("1k").unwrap(),
+///     EQ::from_str("10k").unwrap(),
+///     EQ::from_str("1k").unwrap(),
+/// ).unwrap();
+/// let v: Vec<String> = sweep.map(|q| q.to_string()).collect();
+/// assert_eq!(v, ["1k", "2k", "3k", "4k", "5k", "6k", "7k", "8k", "9k", "10k"]);
+/// ```
+#[derive(Clone, Debug)]
+#[allow(missing_copy_implementations)] // Iterators should not be Copy
+pub struct LinearSweep<T: EQSupported<T>> {
+    /// The next significand to be output, or None if the sweep is complete
+    next: Option<T>,
+    increment: T,
+    stop: T,
+    /// Common exponent for all of the significands
+    exponent: i8,
+}
+
+impl<T: EQSupported<T>> LinearSweep<T> {
+    /// Creates a linear sweep from `start` to `stop` (inclusive) in steps of `increment`.
+    ///
+    /// The increment must be non-zero and must point from `start` towards `stop`.
+    /// Construction fails if the quantities cannot be expressed in terms of a common exponent
+    /// without overflowing `T`.
+    pub fn new(
+        start: EngineeringQuantity<T>,
+        stop: EngineeringQuantity<T>,
+        increment: EngineeringQuantity<T>,
+    ) -> Result<Self, Error> {
+        let exponent = start.exponent.min(stop.exponent).min(increment.exponent);
+        let (start, stop, increment) = (
+            start.significand_at(exponent)?,
+            stop.significand_at(exponent)?,
+            increment.significand_at(exponent)?,
+        );
+        if increment == T::ZERO || (increment > T::ZERO) != (stop >= start) {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(Self {
+            next: Some(start),
+            increment,
+            stop,
+            exponent,
+        })
+    }
+}
+
+impl<T: EQSupported<T>> Iterator for LinearSweep<T> {
+    type Item = EngineeringQuantity<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.clone()?;
+        let beyond_stop = if self.increment > T::ZERO {
+            current > self.stop
+        } else {
+            current < self.stop
+        };
+        if beyond_stop {
+            self.next = None;
+            return None;
+        }
+        // If this overflows, we have necessarily passed the stop value
+        self.next = current.checked_add(&self.increment);
+        let exponent = if current == T::ZERO { 0 } else { self.exponent };
+        Some(EngineeringQuantity::from_raw_unchecked(current, exponent))
+    }
+}
+
+impl<T: EQSupported<T>> FusedIterator for LinearSweep<T> {}
+
+/////////////////////////////////////////////////////////////////////////
+// DECADE-BASED (LOGARITHMIC, E-SERIES)
+
+/// The IEC 60063 preferred number series, commonly used for component values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ESeries {
+    E3,
+    E6,
+    E12,
+    E24,
+    E48,
+    E96,
+    E192,
+}
+
+/// The E24 series, from which E3, E6 and E12 are derived
+const E24: [u16; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30, 33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+/// The E192 series, from which E48 and E96 are derived
+const E192: [u16; 192] = [
+    100, 101, 102, 104, 105, 106, 107, 109, 110, 111, 113, 114, 115, 117, 118, 120, 121, 123, 124,
+    126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 142, 143, 145, 147, 149, 150, 152, 154, 156,
+    158, 160, 162, 164, 165, 167, 169, 172, 174, 176, 178, 180, 182, 184, 187, 189, 191, 193, 196,
+    198, 200, 203, 205, 208, 210, 213, 215, 218, 221, 223, 226, 229, 232, 234, 237, 240, 243, 246,
+    249, 252, 255, 258, 261, 264, 267, 271, 274, 277, 280, 284, 287, 291, 294, 298, 301, 305, 309,
+    312, 316, 320, 324, 328, 332, 336, 340, 344, 348, 352, 357, 361, 365, 370, 374, 379, 383, 388,
+    392, 397, 402, 407, 412, 417, 422, 427, 432, 437, 442, 448, 453, 459, 464, 470, 475, 481, 487,
+    493, 499, 505, 511, 517, 523, 530, 536, 542, 549, 556, 562, 569, 576, 583, 590, 597, 604, 612,
+    619, 626, 634, 642, 649, 657, 665, 673, 681, 690, 698, 706, 715, 723, 732, 741, 750, 759, 768,
+    777, 787, 796, 806, 816, 825, 835, 845, 856, 866, 876, 887, 898, 909, 920, 931, 942, 953, 965,
+    976, 988,
+];
+
+impl ESeries {
+    /// The number of significant figures in each value of this series
+    #[must_use]
+    pub fn significant_figures(self) -> u8 {
+        match self {
+            ESeries::E3 | ESeries::E6 | ESeries::E12 | ESeries::E24 => 2,
+            ESeries::E48 | ESeries::E96 | ESeries::E192 => 3,
+        }
+    }
+
+    /// The values in a single decade of this series, as integers with [`Self::significant_figures()`] digits.
+    /// ```
+    /// use engineering_repr::ESeries;
+    /// let v: Vec<u16> = ESeries::E6.values().collect();
+    /// assert_eq!(v, [10, 15, 22, 33, 47, 68]);
+    /// ```
+    pub fn values(self) -> impl Iterator<Item = u16> {
+        let (table, step): (&'static [u16], usize) = match self {
+            ESeries::E3 => (&E24, 8),
+            ESeries::E6 => (&E24, 4),
+            ESeries::E12 => (&E24, 2),
+            ESeries::E24 => (&E24, 1),
+            ESeries::E48 => (&E192, 4),
+            ESeries::E96 => (&E192, 2),
+            ESeries::E192 => (&E192, 1),
+        };
+        table.iter().copied().step_by(step)
+    }
+}
+
+/// An iterator over quantities which repeat the same pattern of significant figures in every decade.
+///
+/// This type is created by [`DecadeSweep::log()`] (logarithmically spaced points) and
+/// [`DecadeSweep::e_series()`] (preferred values).
+///
+/// Every point is an exact decimal value; decade boundaries (1, 10, 100, ...) are always hit precisely.
+#[derive(Clone, Debug)]
+pub struct DecadeSweep<T: EQSupported<T>> {
+    /// Significant figures of the points within one decade, in ascending order
+    mantissas: Vec<u16>,
+    /// Number of digits in each mantissa
+    digits: i32,
+    /// Power of ten of the most significant digit of the current decade
+    decade: i32,
+    /// Index of the next mantissa to output
+    index: usize,
+    start: EngineeringQuantity<T>,
+    stop: EngineeringQuantity<T>,
+    done: bool,
+}
+
+impl<T: EQSupported<T>> DecadeSweep<T> {
+    /// Creates a logarithmic sweep with `points_per_decade` points in each decade,
+    /// covering the range from `start` to `stop` (inclusive).
+    ///
+    /// The points are rounded to 3 significant figures.
+    /// Both `start` and `stop` must be positive, and `start` must not exceed `stop`.
+    /// ```
+    /// use engineering_repr::{DecadeSweep, EngineeringQuantity as EQ};
+    /// let sweep = DecadeSweep::log(EQ::<u32>::from(10u32), EQ::from(100u32), 5).unwrap();
+    /// let v: Vec<String> = sweep.map(|q| q.to_string()).collect();
+    /// assert_eq!(v, ["10", "15.8", "25.1", "39.8", "63.1", "100"]);
+    /// ```
+    pub fn log(
+        start: EngineeringQuantity<T>,
+        stop: EngineeringQuantity<T>,
+        points_per_decade: u16,
+    ) -> Result<Self, Error> {
+        if points_per_decade == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let mut mantissas: Vec<u16> = (0..points_per_decade)
+            .map(|i| {
+                // The float calculation is only used to build the table for one decade, so errors cannot accumulate.
+                let m = 100.0 * 10f64.powf(f64::from(i) / f64::from(points_per_decade));
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let m = m.round() as u16;
+                m
+            })
+            .filter(|m| *m < 1000)
+            .collect();
+        mantissas.dedup();
+        Self::new(start, stop, mantissas, 3)
+    }
+
+    /// Creates a sweep over the values in an [`ESeries`] which lie between `start` and `stop` (inclusive).
+    ///
+    /// Both `start` and `stop` must be positive, and `start` must not exceed `stop`.
+    /// ```
+    /// use engineering_repr::{DecadeSweep, ESeries, EngineeringQuantity as EQ};
+    /// use std::str::FromStr as _;
+    /// let sweep = DecadeSweep::e_series(
+    ///     ESeries::E6,
+    ///     EQ::<u32>::from_str("1k").unwrap(),
+    ///     EQ::from_str("10k").unwrap(),
+    /// ).unwrap();
+    /// let v: Vec<String> = sweep.map(|q| q.to_string()).collect();
+    /// assert_eq!(v, ["1k", "1.5k", "2.2k", "3.3k", "4.7k", "6.8k", "10k"]);
+    /// ```
+    pub fn e_series(
+        series: ESeries,
+        start: EngineeringQuantity<T>,
+        stop: EngineeringQuantity<T>,
+    ) -> Result<Self, Error> {
+        Self::new(
+            start,
+            stop,
+            series.values().collect(),
+            series.significant_figures().into(),
+        )
+    }
+
+    fn new(
+        start: EngineeringQuantity<T>,
+        stop: EngineeringQuantity<T>,
+        mantissas: Vec<u16>,
+        digits: i32,
+    ) -> Result<Self, Error> {
+        if start.significand <= T::ZERO || stop < start {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(Self {
+            mantissas,
+            digits,
+            decade: start.decade(),
+            index: 0,
+            start,
+            stop,
+            done: false,
+        })
+    }
+}
+
+impl<T: EQSupported<T>> Iterator for DecadeSweep<T> {
+    type Item = EngineeringQuantity<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.index >= self.mantissas.len() {
+                self.index = 0;
+                self.decade += 1;
+            }
+            let mantissa = self.mantissas[self.index];
+            self.index += 1;
+            let pow10 = i8::try_from(self.decade + 1 - self.digits).ok();
+            let Some(value) = T::from_u16(mantissa)
+                .zip(pow10)
+                .and_then(|(m, e)| DecimalQuantity::from_raw(m, e).try_into().ok())
+            else {
+                // If the value cannot be represented, we have necessarily passed the stop value
+                self.done = true;
+                break;
+            };
+            if value > self.stop {
+                self.done = true;
+            } else if value >= self.start {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<T: EQSupported<T>> FusedIterator for DecadeSweep<T> {}
+
+/////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr as _;
+
+    use super::{DecadeSweep, ESeries, LinearSweep};
+    use crate::EngineeringQuantity as EQ;
+    use crate::Error;
+
+    fn strings<I: Iterator<Item = EQ<i64>>>(it: I) -> Vec<String> {
+        it.map(|q| q.with_precision(0).to_string()).collect()
+    }
+
+    fn eq(s: &str) -> EQ<i64> {
+        EQ::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn linear() {
+        let s = LinearSweep::new(eq("0"), eq("1"), eq("100m")).unwrap();
+        assert_eq!(
+            strings(s),
+            ["0", "100m", "200m", "300m", "400m", "500m", "600m", "700m", "800m", "900m", "1"]
+        );
+        // stop value need not be hit exactly
+        let s = LinearSweep::new(eq("1k"), eq("2.5k"), eq("1k")).unwrap();
+        assert_eq!(strings(s), ["1k", "2k"]);
+        // downwards
+        let s = LinearSweep::new(eq("1.5"), eq("-1.5"), eq("-1")).unwrap();
+        assert_eq!(strings(s), ["1.5", "500m", "-500m", "-1.5"]);
+        // single point
+        let s = LinearSweep::new(eq("1M"), eq("1M"), eq("1")).unwrap();
+        assert_eq!(strings(s), ["1M"]);
+    }
+
+    #[test]
+    fn linear_no_drift() {
+        let s = LinearSweep::new(eq("0"), eq("1k"), eq("1m")).unwrap();
+        let last = s.last().unwrap();
+        assert_eq!(last, eq("1k"));
+    }
+
+    #[test]
+    fn linear_overflow() {
+        let start = EQ::<u16>::from(65_530u16);
+        let stop = EQ::<u16>::from(u16::MAX);
+        let increment = EQ::<u16>::from(4u16);
+        let s = LinearSweep::new(start, stop, increment).unwrap();
+        assert_eq!(s.count(), 2);
+    }
+
+    #[test]
+    fn linear_errors() {
+        for (start, stop, incr, err) in &[
+            ("1", "2", "0", Error::InvalidArgument),
+            ("1", "2", "-1", Error::InvalidArgument),
+            ("2", "1", "1", Error::InvalidArgument),
+            ("1E", "2E", "1a", Error::Overflow),
+        ] {
+            let r = LinearSweep::new(eq(start), eq(stop), eq(incr));
+            assert_eq!(r.unwrap_err(), *err, "case {start} {stop} {incr}");
+        }
+    }
+
+    #[test]
+    fn log() {
+        let s = DecadeSweep::log(eq("10"), eq("100k"), 1).unwrap();
+        assert_eq!(strings(s), ["10", "100", "1k", "10k", "100k"]);
+        let s = DecadeSweep::log(eq("1m"), eq("10m"), 10).unwrap();
+        assert_eq!(
+            strings(s),
+            [
+                "1m", "1.26m", "1.58m", "2m", "2.51m", "3.16m", "3.98m", "5.01m", "6.31m", "7.94m",
+                "10m"
+            ]
+        );
+        // partial decades
+        let s = DecadeSweep::log(eq("300"), eq("3k"), 3).unwrap();
+        assert_eq!(strings(s), ["464", "1k", "2.15k"]);
+    }
+
+    #[test]
+    fn log_errors() {
+        for (start, stop, n) in &[("0", "1", 1), ("-1", "1", 1), ("2", "1", 1), ("1", "2", 0)] {
+            let r = DecadeSweep::log(eq(start), eq(stop), *n);
+            assert_eq!(r.unwrap_err(), Error::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn e_series() {
+        let s = DecadeSweep::e_series(ESeries::E12, eq("10"), eq("100")).unwrap();
+        assert_eq!(
+            strings(s),
+            ["10", "12", "15", "18", "22", "27", "33", "39", "47", "56", "68", "82", "100"]
+        );
+        let s = DecadeSweep::e_series(ESeries::E96, eq("9k"), eq("10.5k")).unwrap();
+        assert_eq!(
+            strings(s),
+            ["9.09k", "9.31k", "9.53k", "9.76k", "10k", "10.2k", "10.5k"]
+        );
+        let s = DecadeSweep::e_series(ESeries::E3, eq("1p"), eq("1n")).unwrap();
+        assert_eq!(
+            strings(s),
+            ["1p", "2.2p", "4.7p", "10p", "22p", "47p", "100p", "220p", "470p", "1n"]
+        );
+    }
+
+    #[test]
+    fn e_series_sizes() {
+        for (series, n) in &[
+            (ESeries::E3, 3),
+            (ESeries::E6, 6),
+            (ESeries::E12, 12),
+            (ESeries::E24, 24),
+            (ESeries::E48, 48),
+            (ESeries::E96, 96),
+            (ESeries::E192, 192),
+        ] {
+            assert_eq!(series.values().count(), *n);
+        }
+    }
+
+    #[test]
+    fn sweep_to_overflow() {
+        let s = DecadeSweep::e_series(
+            ESeries::E3,
+            EQ::<u16>::from(10_000u16),
+            EQ::<u16>::from(u16::MAX),
+        )
+        .unwrap();
+        assert_eq!(s.count(), 3); // 10k, 22k, 47k
+    }
+}